@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Step from `initial` until a state repeats or `limit` steps have been
+/// taken.
+///
+/// Returns every state seen in order, where `states[i]` is the state after
+/// `i` steps, along with the index of the first state that repeated.
+fn explore<S, F>(initial: S, mut step: F, limit: usize) -> (Vec<S>, Option<usize>)
+where
+    S: Hash + Eq + Clone,
+    F: FnMut(&S) -> S,
+{
+    let mut seen = HashMap::from([(initial.clone(), 0)]);
+    let mut states = vec![initial];
+
+    while states.len() <= limit {
+        let next = step(&states[states.len() - 1]);
+        if let Some(&start) = seen.get(&next) {
+            return (states, Some(start));
+        }
+        seen.insert(next.clone(), states.len());
+        states.push(next);
+    }
+
+    (states, None)
+}
+
+/// Find the cycle reached by repeatedly applying `step` to `initial`.
+///
+/// Returns `(mu, lambda)`, where `mu` is the number of steps before the
+/// cycle starts and `lambda` is its length.  Every state is kept until the
+/// cycle is found, and this never returns if the states never repeat.
+///
+/// # Examples
+///
+/// ```
+/// // 3, 9, 27 % 10 = 7, 21 % 10 = 1, 3, ...
+/// let (mu, lambda) = aoc::cycle::find_cycle(3_u32, |n| n * 3 % 10);
+/// assert_eq!((mu, lambda), (0, 4));
+///
+/// // 0, 1, 2, 3, 4, 2, ...
+/// let (mu, lambda) = aoc::cycle::find_cycle(0, |n| if *n < 4 { n + 1 } else { 2 });
+/// assert_eq!((mu, lambda), (2, 3));
+/// ```
+pub fn find_cycle<S, F>(initial: S, step: F) -> (usize, usize)
+where
+    S: Hash + Eq + Clone,
+    F: FnMut(&S) -> S,
+{
+    let (states, start) = explore(initial, step, usize::MAX);
+    let mu = start.expect("states are explored until one repeats");
+    (mu, states.len() - mu)
+}
+
+/// The state after applying `step` to `initial` `n` times, skipping ahead
+/// once a cycle is found so only `mu + lambda` steps are ever taken.
+///
+/// # Examples
+///
+/// ```
+/// let step = |n: &u64| if *n < 4 { n + 1 } else { 2 };
+///
+/// assert_eq!(aoc::cycle::nth_state(0, step, 1), 1);
+/// assert_eq!(aoc::cycle::nth_state(0, step, 5), 2);
+/// assert_eq!(aoc::cycle::nth_state(0, step, 1_000_000_000), 4);
+/// ```
+pub fn nth_state<S, F>(initial: S, step: F, n: usize) -> S
+where
+    S: Hash + Eq + Clone,
+    F: FnMut(&S) -> S,
+{
+    let (mut states, start) = explore(initial, step, n);
+    let index = match start {
+        Some(mu) if n >= states.len() => mu + (n - mu) % (states.len() - mu),
+        _ => n,
+    };
+    states.swap_remove(index)
+}
@@ -1,9 +1,90 @@
 use glam::IVec2;
 use std::collections::HashMap;
 
+mod dense;
+mod parser;
+mod pattern;
+mod region;
+mod symmetry;
+mod wrapping;
+
+pub use dense::DenseGrid;
+pub use parser::{parse, parse_dense, parse_pattern, GridParseError, Markers};
+pub use pattern::{find_pattern, find_pattern_oriented};
+pub use region::{flood_fill, regions, Region};
+pub use symmetry::{
+    flip_horizontal, flip_vertical, orient, orientations, rotate_180, rotate_ccw, rotate_cw,
+    Orientation,
+};
+pub use wrapping::{TiledGrid, WrappingGrid};
+
 pub type Grid<T> = HashMap<IVec2, T>;
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+/// Common read-only access to a two dimensional grid of values.
+///
+/// This is implemented for both the sparse, `HashMap` backed [`Grid`] and the
+/// contiguous [`DenseGrid`], so the free functions in this module can operate
+/// on either representation.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::grid::{DenseGrid, Direction, Grid};
+/// use glam::IVec2;
+///
+/// let dense = DenseGrid::from_rows(vec![vec![1, 2, 3], vec![0, 0, 0]]).unwrap();
+/// let sparse = Grid::from(dense.clone());
+///
+/// let target = vec![1, 2, 3];
+/// assert_eq!(
+///     aoc::grid::word_search(&dense, &target),
+///     vec![(IVec2::new(0, 0), Direction::East)]
+/// );
+/// assert_eq!(
+///     aoc::grid::word_search(&sparse, &target),
+///     aoc::grid::word_search(&dense, &target)
+/// );
+/// ```
+pub trait GridLike {
+    type Item;
+
+    /// Get an optional reference to the value at the given point.
+    fn get(&self, point: &IVec2) -> Option<&Self::Item>;
+
+    /// Iterate over every populated point in the grid along with its value.
+    fn cells(&self) -> impl Iterator<Item = (IVec2, &Self::Item)>;
+
+    /// Returns a tuple of vectors representing the top-left and bottom-right
+    /// corners of the grid.
+    fn boundaries(&self) -> (IVec2, IVec2);
+}
+
+impl<T> GridLike for Grid<T> {
+    type Item = T;
+
+    fn get(&self, point: &IVec2) -> Option<&T> {
+        HashMap::get(self, point)
+    }
+
+    fn cells(&self) -> impl Iterator<Item = (IVec2, &T)> {
+        self.iter().map(|(point, value)| (*point, value))
+    }
+
+    fn boundaries(&self) -> (IVec2, IVec2) {
+        let min_x = self.keys().map(|p| p.x).min().unwrap_or(0);
+        let min_y = self.keys().map(|p| p.y).min().unwrap_or(0);
+        let max_x = self.keys().map(|p| p.x).max().unwrap_or(0);
+        let max_y = self.keys().map(|p| p.y).max().unwrap_or(0);
+
+        (IVec2::new(min_x, min_y), IVec2::new(max_x, max_y))
+    }
+}
+
+/// One of the eight compass directions, with north pointing towards
+/// negative `y`.
+///
+/// Variants are ordered clockwise starting from north.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub enum Direction {
     North,
     NorthEast,
@@ -15,6 +96,183 @@ pub enum Direction {
     NorthWest,
 }
 
+impl Direction {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// The four cardinal directions, clockwise from north.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Rotate the direction by the given number of eighth turns clockwise.
+    fn rotate(self, eighths: usize) -> Self {
+        Self::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// The direction after a 90 degree turn to the left.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::grid::Direction;
+    ///
+    /// assert_eq!(Direction::North.turn_left(), Direction::West);
+    /// assert_eq!(Direction::SouthEast.turn_left(), Direction::NorthEast);
+    /// ```
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    /// The direction after a 90 degree turn to the right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::grid::Direction;
+    ///
+    /// assert_eq!(Direction::North.turn_right(), Direction::East);
+    /// assert_eq!(Direction::NorthWest.turn_right(), Direction::NorthEast);
+    /// ```
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// The direction pointing the opposite way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::grid::Direction;
+    ///
+    /// assert_eq!(Direction::North.opposite(), Direction::South);
+    /// assert_eq!(Direction::SouthWest.opposite(), Direction::NorthEast);
+    /// ```
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    /// Returns `true` for north, east, south and west.
+    pub fn is_cardinal(self) -> bool {
+        Self::CARDINAL.contains(&self)
+    }
+
+    /// The unit step taken when moving in this direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::grid::Direction;
+    /// use glam::IVec2;
+    ///
+    /// assert_eq!(Direction::North.to_ivec2(), IVec2::new(0, -1));
+    /// assert_eq!(Direction::SouthEast.to_ivec2(), IVec2::new(1, 1));
+    /// ```
+    pub fn to_ivec2(self) -> IVec2 {
+        match self {
+            Direction::North => IVec2::new(0, -1),
+            Direction::NorthEast => IVec2::new(1, -1),
+            Direction::East => IVec2::new(1, 0),
+            Direction::SouthEast => IVec2::new(1, 1),
+            Direction::South => IVec2::new(0, 1),
+            Direction::SouthWest => IVec2::new(-1, 1),
+            Direction::West => IVec2::new(-1, 0),
+            Direction::NorthWest => IVec2::new(-1, -1),
+        }
+    }
+
+    /// The direction of a unit step, or `None` if the vector is not one of
+    /// the eight unit steps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::grid::Direction;
+    /// use glam::IVec2;
+    ///
+    /// assert_eq!(Direction::from_ivec2(IVec2::new(-1, 0)), Some(Direction::West));
+    /// assert_eq!(Direction::from_ivec2(IVec2::new(2, 0)), None);
+    /// assert_eq!(Direction::from_ivec2(IVec2::ZERO), None);
+    /// ```
+    pub fn from_ivec2(vector: IVec2) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|direction| direction.to_ivec2() == vector)
+    }
+
+    /// The direction a beam travelling this way leaves a `/` mirror in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::grid::Direction;
+    ///
+    /// assert_eq!(Direction::East.reflect_slash(), Direction::North);
+    /// assert_eq!(Direction::South.reflect_slash(), Direction::West);
+    /// assert_eq!(Direction::NorthEast.reflect_slash(), Direction::NorthEast);
+    /// ```
+    pub fn reflect_slash(self) -> Self {
+        let IVec2 { x, y } = self.to_ivec2();
+        Self::from_ivec2(IVec2::new(-y, -x)).expect("a reflected unit step is a unit step")
+    }
+
+    /// The direction a beam travelling this way leaves a `\` mirror in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::grid::Direction;
+    ///
+    /// assert_eq!(Direction::East.reflect_backslash(), Direction::South);
+    /// assert_eq!(Direction::North.reflect_backslash(), Direction::West);
+    /// assert_eq!(Direction::NorthWest.reflect_backslash(), Direction::NorthWest);
+    /// ```
+    pub fn reflect_backslash(self) -> Self {
+        let IVec2 { x, y } = self.to_ivec2();
+        Self::from_ivec2(IVec2::new(y, x)).expect("a reflected unit step is a unit step")
+    }
+}
+
+/// Which neighbors of a cell count as adjacent to it.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Connectivity {
+    /// Only the four orthogonal neighbors.
+    Four,
+    /// The orthogonal and diagonal neighbors.
+    Eight,
+}
+
+impl Connectivity {
+    /// The directions of every adjacent neighbor, clockwise from north.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::grid::{Connectivity, Direction};
+    ///
+    /// assert_eq!(Connectivity::Four.directions().len(), 4);
+    /// assert_eq!(Connectivity::Eight.directions()[1], Direction::NorthEast);
+    /// ```
+    pub fn directions(self) -> &'static [Direction] {
+        match self {
+            Connectivity::Four => &Direction::CARDINAL,
+            Connectivity::Eight => &Direction::ALL,
+        }
+    }
+}
+
 /// Calculcate the next point in the given direction.
 ///
 /// # Examples
@@ -50,16 +308,7 @@ pub enum Direction {
 /// ```
 ///
 pub fn next_point(point: &IVec2, direction: &Direction) -> IVec2 {
-    match direction {
-        Direction::North => IVec2::new(point.x, point.y - 1),
-        Direction::NorthEast => IVec2::new(point.x + 1, point.y - 1),
-        Direction::East => IVec2::new(point.x + 1, point.y),
-        Direction::SouthEast => IVec2::new(point.x + 1, point.y + 1),
-        Direction::South => IVec2::new(point.x, point.y + 1),
-        Direction::SouthWest => IVec2::new(point.x - 1, point.y + 1),
-        Direction::West => IVec2::new(point.x - 1, point.y),
-        Direction::NorthWest => IVec2::new(point.x - 1, point.y - 1),
-    }
+    *point + direction.to_ivec2()
 }
 
 /// Get an optional reference to the value at the next point in the given direction.
@@ -83,7 +332,11 @@ pub fn next_point(point: &IVec2, direction: &Direction) -> IVec2 {
 /// assert_eq!(next, IVec2::new(0, 1));
 /// assert_eq!(value, None);
 /// ```
-pub fn travel<'a, T>(grid: &'a Grid<T>, point: &IVec2, direction: Direction) -> (IVec2, Option<&'a T>) {
+pub fn travel<'a, G: GridLike>(
+    grid: &'a G,
+    point: &IVec2,
+    direction: Direction,
+) -> (IVec2, Option<&'a G::Item>) {
     let next = next_point(point, &direction);
     (next, grid.get(&next))
 }
@@ -163,12 +416,14 @@ pub fn columns<T>(grid: &Grid<T>) -> Option<Vec<Vec<&T>>> {
 /// Returns a HashMap of the immediate neighbors of a point in the given grid.
 ///
 /// The returned map is keyed by `Direction` and the value is a tuple of the
-/// neighbor's coordinates and the optional value at that point in the grid.
+/// neighbor's coordinates and the value at that point in the grid.  Only
+/// neighbors inside the grid are included; see [`adjacent`] for a variant
+/// that does not allocate.
 ///
 /// # Examples
 ///
 /// ```
-/// use aoc::grid::{Grid, Direction};
+/// use aoc::grid::{Connectivity, Grid, Direction};
 /// use glam::IVec2;
 ///
 /// let mut grid = Grid::new();
@@ -177,57 +432,96 @@ pub fn columns<T>(grid: &Grid<T>) -> Option<Vec<Vec<&T>>> {
 /// grid.insert(IVec2::new(0, 1), 3);
 /// grid.insert(IVec2::new(1, 1), 4);
 ///
-/// let neighbors = aoc::grid::neighbors(&grid, &IVec2::new(0, 0));
+/// let neighbors = aoc::grid::neighbors(&grid, &IVec2::new(0, 0), Connectivity::Eight);
 /// assert_eq!(neighbors[&Direction::East], (IVec2::new(1, 0), &2));
 /// assert_eq!(neighbors[&Direction::SouthEast], (IVec2::new(1, 1), &4));
 /// assert_eq!(neighbors[&Direction::South], (IVec2::new(0, 1), &3));
+///
+/// let neighbors = aoc::grid::neighbors(&grid, &IVec2::new(0, 0), Connectivity::Four);
+/// assert!(!neighbors.contains_key(&Direction::SouthEast));
 /// ```
 ///
-pub fn neighbors<'a, T>(grid: &'a Grid<T>, point: &IVec2) -> HashMap<Direction, (IVec2, &'a T)> {
-    let mut neighbors = HashMap::new();
-
-    let north = IVec2::new(point.x, point.y - 1);
-    let north_east = IVec2::new(point.x + 1, point.y - 1);
-    let east = IVec2::new(point.x + 1, point.y);
-    let south_east = IVec2::new(point.x + 1, point.y + 1);
-    let south = IVec2::new(point.x, point.y + 1);
-    let south_west = IVec2::new(point.x - 1, point.y + 1);
-    let west = IVec2::new(point.x - 1, point.y);
-    let north_west = IVec2::new(point.x - 1, point.y - 1);
-
-    if let Some(value) = grid.get(&north) {
-        neighbors.insert(Direction::North, (north, value));
-    }
-
-    if let Some(value) = grid.get(&north_east) {
-        neighbors.insert(Direction::NorthEast, (north_east, value));
-    }
-
-    if let Some(value) = grid.get(&east) {
-        neighbors.insert(Direction::East, (east, value));
-    }
-
-    if let Some(value) = grid.get(&south_east) {
-        neighbors.insert(Direction::SouthEast, (south_east, value));
-    }
-
-    if let Some(value) = grid.get(&south) {
-        neighbors.insert(Direction::South, (south, value));
-    }
-
-    if let Some(value) = grid.get(&south_west) {
-        neighbors.insert(Direction::SouthWest, (south_west, value));
-    }
-
-    if let Some(value) = grid.get(&west) {
-        neighbors.insert(Direction::West, (west, value));
-    }
+pub fn neighbors<'a, G: GridLike>(
+    grid: &'a G,
+    point: &IVec2,
+    connectivity: Connectivity,
+) -> HashMap<Direction, (IVec2, &'a G::Item)> {
+    adjacent(grid, point, connectivity)
+        .map(|(direction, next, value)| (direction, (next, value)))
+        .collect()
+}
 
-    if let Some(value) = grid.get(&north_west) {
-        neighbors.insert(Direction::NorthWest, (north_west, value));
-    }
+/// Iterate over the neighbors of a point that are inside the grid, without
+/// allocating.
+///
+/// Neighbors are yielded clockwise from north as tuples of the direction
+/// taken, the neighbor's coordinates and its value.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::grid::{Connectivity, Direction};
+/// use glam::IVec2;
+///
+/// let grid = aoc::grid::locate(vec![vec![1, 2], vec![3, 4]]);
+///
+/// let four = aoc::grid::adjacent(&grid, &IVec2::new(0, 0), Connectivity::Four).collect::<Vec<_>>();
+/// assert_eq!(
+///     four,
+///     vec![
+///         (Direction::East, IVec2::new(1, 0), &2),
+///         (Direction::South, IVec2::new(0, 1), &3),
+///     ]
+/// );
+///
+/// let eight = aoc::grid::adjacent(&grid, &IVec2::new(0, 0), Connectivity::Eight);
+/// assert_eq!(eight.count(), 3);
+/// ```
+pub fn adjacent<'a, G: GridLike>(
+    grid: &'a G,
+    point: &IVec2,
+    connectivity: Connectivity,
+) -> impl Iterator<Item = (Direction, IVec2, &'a G::Item)> + 'a {
+    surrounding(grid, point, connectivity)
+        .filter_map(|(direction, next, value)| Some((direction, next, value?)))
+}
 
-    neighbors
+/// Iterate over every neighbor of a point, including those outside the grid,
+/// without allocating.
+///
+/// Neighbors outside the grid are yielded with a value of `None`, which makes
+/// edge handling explicit.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::grid::{Connectivity, Direction};
+/// use glam::IVec2;
+///
+/// let grid = aoc::grid::locate(vec![vec![1, 2], vec![3, 4]]);
+///
+/// let four = aoc::grid::surrounding(&grid, &IVec2::new(0, 0), Connectivity::Four)
+///     .collect::<Vec<_>>();
+/// assert_eq!(
+///     four,
+///     vec![
+///         (Direction::North, IVec2::new(0, -1), None),
+///         (Direction::East, IVec2::new(1, 0), Some(&2)),
+///         (Direction::South, IVec2::new(0, 1), Some(&3)),
+///         (Direction::West, IVec2::new(-1, 0), None),
+///     ]
+/// );
+/// ```
+pub fn surrounding<'a, G: GridLike>(
+    grid: &'a G,
+    point: &IVec2,
+    connectivity: Connectivity,
+) -> impl Iterator<Item = (Direction, IVec2, Option<&'a G::Item>)> + 'a {
+    let point = *point;
+    connectivity.directions().iter().map(move |direction| {
+        let next = next_point(&point, direction);
+        (*direction, next, grid.get(&next))
+    })
 }
 
 /// Returns a tuple of vectors representing the top-left and bottom-right
@@ -251,13 +545,8 @@ pub fn neighbors<'a, T>(grid: &'a Grid<T>, point: &IVec2) -> HashMap<Direction,
 /// assert_eq!(bottom_right, IVec2::new(1, 1));
 /// ```
 ///
-pub fn boundaries<T>(grid: &Grid<T>) -> (IVec2, IVec2) {
-    let min_x = grid.keys().map(|p| p.x).min().unwrap_or(0);
-    let min_y = grid.keys().map(|p| p.y).min().unwrap_or(0);
-    let max_x = grid.keys().map(|p| p.x).max().unwrap_or(0);
-    let max_y = grid.keys().map(|p| p.y).max().unwrap_or(0);
-
-    (IVec2::new(min_x, min_y), IVec2::new(max_x, max_y))
+pub fn boundaries<G: GridLike>(grid: &G) -> (IVec2, IVec2) {
+    grid.boundaries()
 }
 
 /// Applies a filter function to the grid's keys and returns a new grid
@@ -309,10 +598,14 @@ pub fn filter_keys<T: Clone>(grid: &Grid<T>, filter_fn: impl Fn(&IVec2) -> bool)
 /// assert_eq!(filtered[&IVec2::new(1, 1)], 4);
 /// ```
 ///
-pub fn filter_values<T: Clone>(grid: &Grid<T>, filter_fn: impl Fn(&T) -> bool) -> Grid<T> {
-    grid.iter()
+pub fn filter_values<G>(grid: &G, filter_fn: impl Fn(&G::Item) -> bool) -> Grid<G::Item>
+where
+    G: GridLike,
+    G::Item: Clone,
+{
+    grid.cells()
         .filter(|(_, value)| filter_fn(value))
-        .map(|(point, value)| (*point, (*value).clone()))
+        .map(|(point, value)| (point, value.clone()))
         .collect()
 }
 
@@ -375,6 +668,9 @@ pub fn map_values<T: Clone>(grid: &Grid<T>, map_fn: impl Fn(&T) -> T) -> Grid<T>
 /// Returns a new grid with the rows and columns swapped.
 /// This is useful for rotating a grid 90 degrees.
 ///
+/// Unlike [`orient`] with [`Orientation::Transpose`], the grid is not
+/// normalized to the origin first.
+///
 /// # Examples
 ///
 /// ```
@@ -535,8 +831,10 @@ pub fn locate<T>(rows: Vec<Vec<T>>) -> Grid<T> {
 /// assert!(results.contains(&(IVec2::new(2, 0), Direction::SouthWest)));
 /// ```
 /// 
-pub fn word_search<T>(grid: &Grid<T>, target: &[T]) -> Vec<(IVec2, Direction)> 
-where T: PartialEq + Clone + std::fmt::Debug
+pub fn word_search<G>(grid: &G, target: &[G::Item]) -> Vec<(IVec2, Direction)>
+where
+    G: GridLike,
+    G::Item: PartialEq + Clone,
 {
     // Base condition
     if target.is_empty() {
@@ -546,41 +844,38 @@ where T: PartialEq + Clone + std::fmt::Debug
     let starting_positions = filter_values(grid, |tile| *tile == target[0]);
 
     if target.len() == 1 {
-        return starting_positions.keys().map(|pos| (pos.clone(), Direction::East)).collect();
+        return starting_positions
+            .into_keys()
+            .map(|pos| (pos, Direction::East))
+            .collect();
     }
 
     let vectors = starting_positions
-        .iter()
-        .flat_map(|(start, _)| {
-
-            neighbors(grid, start)
-                .into_iter()
-                .filter(|(_, (_, current_tile))| {
-                    **current_tile == target[1]
-                })
-                .map(|(direction, _)| (start.clone(), direction.clone()))
-            }).collect::<Vec<(IVec2, Direction)>>();
-
-     vectors
+        .keys()
+        .flat_map(|start| {
+            adjacent(grid, start, Connectivity::Eight)
+                .filter(|(_, _, current_tile)| **current_tile == target[1])
+                .map(|(direction, _, _)| (*start, direction))
+        })
+        .collect::<Vec<(IVec2, Direction)>>();
+
+    vectors
         .into_iter()
         .filter(|(start, direction)| {
-
-            let mut c_pos = start.clone();
+            let mut c_pos = *start;
             for tile in target[1..].iter() {
-                let (next, value) = travel(grid, &c_pos, direction.clone());
+                let (next, value) = travel(grid, &c_pos, *direction);
 
                 if value != Some(tile) {
                     return false;
                 }
-                c_pos = next.clone();
+                c_pos = next;
             }
             true
         })
         .collect()
-
 }
 
-
 /// Print the grid to the console.
 /// 
 /// # Examples
@@ -600,11 +895,13 @@ where T: PartialEq + Clone + std::fmt::Debug
 /// 
 /// aoc::grid::print_grid(&grid);
 /// ```
-pub fn print_grid<T>(grid: &Grid<T>) 
-where T: Default + std::fmt::Display
+pub fn print_grid<G>(grid: &G)
+where
+    G: GridLike,
+    G::Item: Default + std::fmt::Display,
 {
-    let default = T::default();
-    let (top_left, bottom_right) = boundaries(&grid);
+    let default = G::Item::default();
+    let (top_left, bottom_right) = boundaries(grid);
     for y in top_left.y..=bottom_right.y {
         for x in top_left.x..=bottom_right.x {
             let value = grid.get(&IVec2::new(x, y)).unwrap_or(&default);
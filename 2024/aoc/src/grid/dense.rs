@@ -0,0 +1,356 @@
+use glam::IVec2;
+use std::ops::{Index, IndexMut};
+
+use super::{Grid, GridLike};
+
+/// A contiguous, bounds-aware grid backed by a single `Vec<T>`.
+///
+/// Values are stored in row-major order with the top-left cell at the origin,
+/// so lookups by `IVec2` are a bounds check and an index calculation rather
+/// than a hash.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DenseGrid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> DenseGrid<T> {
+    /// Create a new grid from a row-major vector of values.
+    ///
+    /// Returns `None` if the number of values does not match the given
+    /// dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::grid::DenseGrid;
+    /// use glam::IVec2;
+    ///
+    /// let grid = DenseGrid::new(2, 2, vec![1, 2, 3, 4]).unwrap();
+    /// assert_eq!(grid[IVec2::new(1, 0)], 2);
+    /// assert_eq!(grid[IVec2::new(0, 1)], 3);
+    ///
+    /// assert!(DenseGrid::new(3, 2, vec![1, 2, 3, 4]).is_none());
+    /// ```
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Option<Self> {
+        if width * height != cells.len() {
+            return None;
+        }
+        Some(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Create a new grid of the given dimensions where every cell holds a
+    /// copy of `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::grid::DenseGrid;
+    /// use glam::IVec2;
+    ///
+    /// let grid = DenseGrid::filled(3, 2, '.');
+    /// assert_eq!(grid.width(), 3);
+    /// assert_eq!(grid.height(), 2);
+    /// assert_eq!(grid[IVec2::new(2, 1)], '.');
+    /// ```
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Create a new grid from a 2-dimensional vector of values.
+    ///
+    /// Returns `None` if the rows are not all the same length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::grid::DenseGrid;
+    /// use glam::IVec2;
+    ///
+    /// let rows = vec![
+    ///     vec![1, 2],
+    ///     vec![3, 4],
+    /// ];
+    ///
+    /// let grid = DenseGrid::from_rows(rows).unwrap();
+    /// assert_eq!(grid[IVec2::new(0, 0)], 1);
+    /// assert_eq!(grid[IVec2::new(1, 0)], 2);
+    /// assert_eq!(grid[IVec2::new(0, 1)], 3);
+    /// assert_eq!(grid[IVec2::new(1, 1)], 4);
+    ///
+    /// assert!(DenseGrid::from_rows(vec![vec![1, 2], vec![3]]).is_none());
+    /// ```
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// The number of columns in the grid.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The number of rows in the grid.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The total number of cells in the grid.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns `true` if the grid has no cells.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns `true` if the point lies within the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::grid::DenseGrid;
+    /// use glam::IVec2;
+    ///
+    /// let grid = DenseGrid::filled(2, 2, 0);
+    /// assert!(grid.contains(&IVec2::new(1, 1)));
+    /// assert!(!grid.contains(&IVec2::new(2, 0)));
+    /// assert!(!grid.contains(&IVec2::new(-1, 0)));
+    /// ```
+    pub fn contains(&self, point: &IVec2) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    fn index_of(&self, point: &IVec2) -> Option<usize> {
+        let (x, y) = (
+            usize::try_from(point.x).ok()?,
+            usize::try_from(point.y).ok()?,
+        );
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    /// Get an optional reference to the value at the given point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::grid::DenseGrid;
+    /// use glam::IVec2;
+    ///
+    /// let grid = DenseGrid::new(2, 1, vec![1, 2]).unwrap();
+    /// assert_eq!(grid.get(&IVec2::new(1, 0)), Some(&2));
+    /// assert_eq!(grid.get(&IVec2::new(0, 1)), None);
+    /// ```
+    pub fn get(&self, point: &IVec2) -> Option<&T> {
+        self.index_of(point).map(|index| &self.cells[index])
+    }
+
+    /// Get an optional mutable reference to the value at the given point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::grid::DenseGrid;
+    /// use glam::IVec2;
+    ///
+    /// let mut grid = DenseGrid::new(2, 1, vec![1, 2]).unwrap();
+    /// *grid.get_mut(&IVec2::new(1, 0)).unwrap() = 5;
+    /// assert_eq!(grid[IVec2::new(1, 0)], 5);
+    /// ```
+    pub fn get_mut(&mut self, point: &IVec2) -> Option<&mut T> {
+        self.index_of(point).map(|index| &mut self.cells[index])
+    }
+
+    /// Returns the values of a single row as a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::grid::DenseGrid;
+    ///
+    /// let grid = DenseGrid::new(2, 2, vec![1, 2, 3, 4]).unwrap();
+    /// assert_eq!(grid.row(1), Some(&[3, 4][..]));
+    /// assert_eq!(grid.row(2), None);
+    /// ```
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    /// Returns the values of a single column, top to bottom.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::grid::DenseGrid;
+    ///
+    /// let grid = DenseGrid::new(2, 2, vec![1, 2, 3, 4]).unwrap();
+    /// assert_eq!(grid.column(1).unwrap().collect::<Vec<_>>(), vec![&2, &4]);
+    /// assert!(grid.column(2).is_none());
+    /// ```
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        (x < self.width).then(|| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    /// Iterate over the rows of the grid as slices, top to bottom.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::grid::DenseGrid;
+    ///
+    /// let grid = DenseGrid::new(2, 2, vec![1, 2, 3, 4]).unwrap();
+    /// let rows = grid.rows().collect::<Vec<_>>();
+    /// assert_eq!(rows, vec![&[1, 2][..], &[3, 4][..]]);
+    ///
+    /// let empty = DenseGrid::<u8>::new(0, 3, vec![]).unwrap();
+    /// assert_eq!(empty.rows().count(), 3);
+    /// ```
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    /// Iterate over every point in the grid along with its value, in
+    /// row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::grid::DenseGrid;
+    /// use glam::IVec2;
+    ///
+    /// let grid = DenseGrid::new(2, 1, vec!['a', 'b']).unwrap();
+    /// let cells = grid.iter().collect::<Vec<_>>();
+    /// assert_eq!(cells, vec![(IVec2::new(0, 0), &'a'), (IVec2::new(1, 0), &'b')]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (IVec2, &T)> {
+        let width = self.width.max(1);
+        self.cells.iter().enumerate().map(move |(index, value)| {
+            let point = IVec2::new((index % width) as i32, (index / width) as i32);
+            (point, value)
+        })
+    }
+}
+
+impl<T> GridLike for DenseGrid<T> {
+    type Item = T;
+
+    fn get(&self, point: &IVec2) -> Option<&T> {
+        DenseGrid::get(self, point)
+    }
+
+    fn cells(&self) -> impl Iterator<Item = (IVec2, &T)> {
+        self.iter()
+    }
+
+    fn boundaries(&self) -> (IVec2, IVec2) {
+        let bottom_right = IVec2::new(self.width as i32 - 1, self.height as i32 - 1);
+        (IVec2::ZERO, bottom_right.max(IVec2::ZERO))
+    }
+}
+
+impl<T> Index<IVec2> for DenseGrid<T> {
+    type Output = T;
+
+    fn index(&self, point: IVec2) -> &T {
+        self.get(&point)
+            .unwrap_or_else(|| panic!("point {point} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<IVec2> for DenseGrid<T> {
+    fn index_mut(&mut self, point: IVec2) -> &mut T {
+        self.get_mut(&point)
+            .unwrap_or_else(|| panic!("point {point} is outside the grid"))
+    }
+}
+
+/// Convert a sparse grid into a dense one.
+///
+/// The grid is translated so the top-left corner of its boundaries lands on
+/// the origin, and any holes are filled with `T::default()`.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::grid::{DenseGrid, Grid};
+/// use glam::IVec2;
+///
+/// let mut grid = Grid::new();
+/// grid.insert(IVec2::new(1, 1), 1);
+/// grid.insert(IVec2::new(2, 2), 4);
+///
+/// let dense = DenseGrid::from(&grid);
+/// assert_eq!(dense.width(), 2);
+/// assert_eq!(dense.height(), 2);
+/// assert_eq!(dense[IVec2::new(0, 0)], 1);
+/// assert_eq!(dense[IVec2::new(1, 0)], 0);
+/// assert_eq!(dense[IVec2::new(1, 1)], 4);
+/// ```
+impl<T: Clone + Default> From<&Grid<T>> for DenseGrid<T> {
+    fn from(grid: &Grid<T>) -> Self {
+        if grid.is_empty() {
+            return Self::new(0, 0, vec![]).unwrap();
+        }
+
+        let (top_left, bottom_right) = GridLike::boundaries(grid);
+        let width = (bottom_right.x - top_left.x + 1) as usize;
+        let height = (bottom_right.y - top_left.y + 1) as usize;
+
+        let mut dense = Self::filled(width, height, T::default());
+        for (point, value) in grid {
+            dense[*point - top_left] = value.clone();
+        }
+        dense
+    }
+}
+
+/// Convert a dense grid into a sparse one.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::grid::{DenseGrid, Grid};
+/// use glam::IVec2;
+///
+/// let dense = DenseGrid::new(2, 1, vec![1, 2]).unwrap();
+/// let grid = Grid::from(dense);
+/// assert_eq!(grid.len(), 2);
+/// assert_eq!(grid[&IVec2::new(1, 0)], 2);
+/// ```
+impl<T> From<DenseGrid<T>> for Grid<T> {
+    fn from(dense: DenseGrid<T>) -> Self {
+        let width = dense.width.max(1);
+        dense
+            .cells
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| {
+                let point = IVec2::new((index % width) as i32, (index / width) as i32);
+                (point, value)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,192 @@
+use glam::IVec2;
+use std::collections::HashMap;
+use thiserror::Error;
+
+use super::{DenseGrid, Grid};
+
+/// The positions of every marker character found while parsing a grid.
+pub type Markers = HashMap<char, Vec<IVec2>>;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum GridParseError {
+    #[error("invalid character {character:?} at line {line}, column {column}")]
+    InvalidCharacter {
+        character: char,
+        line: usize,
+        column: usize,
+    },
+
+    #[error("line {line} has {found} columns, expected {expected}")]
+    RaggedRow {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+/// Parse every line of the input into a row of tiles, recording the position
+/// of any character listed in `markers` along the way.
+fn parse_rows<T: TryFrom<char>>(
+    input: &str,
+    markers: &[char],
+) -> Result<(Vec<Vec<T>>, Markers), GridParseError> {
+    let mut found = Markers::new();
+
+    let rows = input
+        .lines()
+        .enumerate()
+        .map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .map(|(x, character)| {
+                    if markers.contains(&character) {
+                        let position = IVec2::new(x as i32, y as i32);
+                        found.entry(character).or_default().push(position);
+                    }
+
+                    T::try_from(character).map_err(|_| GridParseError::InvalidCharacter {
+                        character,
+                        line: y + 1,
+                        column: x + 1,
+                    })
+                })
+                .collect::<Result<Vec<T>, _>>()
+        })
+        .collect::<Result<Vec<Vec<T>>, _>>()?;
+
+    Ok((rows, found))
+}
+
+/// Parse a block of text into a [`Grid`], one tile per character.
+///
+/// Each character is converted with `T::try_from`, and the first one that
+/// fails to convert is reported along with its (1-based) line and column.
+/// The positions of any characters listed in `markers`, such as a start
+/// tile, are returned alongside the grid.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::grid::GridParseError;
+/// use glam::IVec2;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Tile {
+///     Wall,
+///     Open,
+/// }
+///
+/// impl TryFrom<char> for Tile {
+///     type Error = char;
+///
+///     fn try_from(c: char) -> Result<Self, Self::Error> {
+///         match c {
+///             '#' => Ok(Self::Wall),
+///             '.' | 'S' => Ok(Self::Open),
+///             _ => Err(c),
+///         }
+///     }
+/// }
+///
+/// let (grid, markers) = aoc::grid::parse::<Tile>("#.#\n.S.\n", &['S']).unwrap();
+/// assert_eq!(grid.len(), 6);
+/// assert_eq!(grid[&IVec2::new(0, 0)], Tile::Wall);
+/// assert_eq!(markers[&'S'], vec![IVec2::new(1, 1)]);
+///
+/// let error = aoc::grid::parse::<Tile>("#.#\n.x.\n", &[]).unwrap_err();
+/// assert_eq!(
+///     error,
+///     GridParseError::InvalidCharacter { character: 'x', line: 2, column: 2 }
+/// );
+/// ```
+pub fn parse<T: TryFrom<char>>(
+    input: &str,
+    markers: &[char],
+) -> Result<(Grid<T>, Markers), GridParseError> {
+    let (rows, markers) = parse_rows(input, markers)?;
+    Ok((super::locate(rows), markers))
+}
+
+/// Parse a block of text into a [`DenseGrid`], one tile per character.
+///
+/// This behaves like [`parse`], but additionally requires every line to be
+/// the same length.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::grid::GridParseError;
+/// use glam::IVec2;
+///
+/// let (grid, markers) = aoc::grid::parse_dense::<char>("ab\ncd", &['c']).unwrap();
+/// assert_eq!(grid.width(), 2);
+/// assert_eq!(grid[IVec2::new(1, 1)], 'd');
+/// assert_eq!(markers[&'c'], vec![IVec2::new(0, 1)]);
+///
+/// let error = aoc::grid::parse_dense::<char>("ab\nc", &[]).unwrap_err();
+/// assert_eq!(error, GridParseError::RaggedRow { line: 2, expected: 2, found: 1 });
+/// ```
+pub fn parse_dense<T: TryFrom<char>>(
+    input: &str,
+    markers: &[char],
+) -> Result<(DenseGrid<T>, Markers), GridParseError> {
+    let (rows, markers) = parse_rows(input, markers)?;
+
+    let expected = rows.first().map_or(0, |row| row.len());
+    if let Some((y, row)) = rows
+        .iter()
+        .enumerate()
+        .find(|(_, row)| row.len() != expected)
+    {
+        return Err(GridParseError::RaggedRow {
+            line: y + 1,
+            expected,
+            found: row.len(),
+        });
+    }
+
+    let grid = DenseGrid::from_rows(rows).expect("rows are all the same length");
+    Ok((grid, markers))
+}
+
+/// Parse a block of text into a pattern for [`find_pattern`], one tile per
+/// character.
+///
+/// Every `wildcard` character is left out of the grid, so it matches any
+/// value.
+///
+/// [`find_pattern`]: super::find_pattern
+///
+/// # Examples
+///
+/// ```
+/// use glam::IVec2;
+///
+/// let pattern = aoc::grid::parse_pattern::<char>("M.S\n.A.\nM.S", '.').unwrap();
+/// assert_eq!(pattern.len(), 5);
+/// assert_eq!(pattern[&IVec2::new(1, 1)], 'A');
+/// assert!(!pattern.contains_key(&IVec2::new(1, 0)));
+/// ```
+pub fn parse_pattern<T: TryFrom<char>>(
+    input: &str,
+    wildcard: char,
+) -> Result<Grid<T>, GridParseError> {
+    let mut pattern = Grid::new();
+
+    for (y, line) in input.lines().enumerate() {
+        for (x, character) in line.chars().enumerate() {
+            if character == wildcard {
+                continue;
+            }
+
+            let tile = T::try_from(character).map_err(|_| GridParseError::InvalidCharacter {
+                character,
+                line: y + 1,
+                column: x + 1,
+            })?;
+            pattern.insert(IVec2::new(x as i32, y as i32), tile);
+        }
+    }
+
+    Ok(pattern)
+}
@@ -0,0 +1,99 @@
+use glam::IVec2;
+
+use super::{orientations, Grid, GridLike, Orientation};
+
+/// Find every position where the pattern matches the grid.
+///
+/// The pattern is a small sparse grid, and any cell missing from it is a
+/// wildcard that matches anything, including points outside the grid.  The
+/// returned anchors are where the top-left corner of the pattern's
+/// boundaries lands, in reading order.
+///
+/// # Examples
+///
+/// ```
+/// use glam::IVec2;
+///
+/// let grid = aoc::grid::locate(vec![
+///     "ab.".chars().collect(),
+///     "bab".chars().collect(),
+///     ".ba".chars().collect(),
+/// ]);
+/// let pattern = aoc::grid::parse_pattern::<char>("a?\n?a", '?').unwrap();
+///
+/// let anchors = aoc::grid::find_pattern(&grid, &pattern);
+/// assert_eq!(anchors, vec![IVec2::new(0, 0), IVec2::new(1, 1)]);
+/// ```
+pub fn find_pattern<G>(grid: &G, pattern: &Grid<G::Item>) -> Vec<IVec2>
+where
+    G: GridLike,
+    G::Item: PartialEq,
+{
+    let (top_left, _) = pattern.boundaries();
+    let mut cells = pattern
+        .iter()
+        .map(|(point, value)| (*point - top_left, value))
+        .collect::<Vec<_>>();
+    cells.sort_by_key(|(offset, _)| (offset.y, offset.x));
+
+    let Some(((first, key), rest)) = cells.split_first() else {
+        return vec![];
+    };
+
+    let mut anchors = grid
+        .cells()
+        .filter(|(_, value)| value == key)
+        .map(|(point, _)| point - *first)
+        .filter(|anchor| {
+            rest.iter()
+                .all(|(offset, expected)| grid.get(&(*anchor + *offset)) == Some(expected))
+        })
+        .collect::<Vec<_>>();
+    anchors.sort_by_key(|anchor| (anchor.y, anchor.x));
+    anchors
+}
+
+/// Find every position where any of the eight orientations of the pattern
+/// matches the grid.
+///
+/// Orientations that produce the same pattern as an earlier one are skipped,
+/// so a symmetric pattern is only reported once per match.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::grid::Orientation;
+/// use glam::IVec2;
+///
+/// let grid = aoc::grid::locate(vec![
+///     "S.S".chars().collect(),
+///     ".A.".chars().collect(),
+///     "M.M".chars().collect(),
+/// ]);
+/// let pattern = aoc::grid::parse_pattern::<char>("M.S\n.A.\nM.S", '.').unwrap();
+///
+/// let matches = aoc::grid::find_pattern_oriented(&grid, &pattern);
+/// assert_eq!(matches, vec![(IVec2::new(0, 0), Orientation::RotateCcw)]);
+/// ```
+pub fn find_pattern_oriented<G>(grid: &G, pattern: &Grid<G::Item>) -> Vec<(IVec2, Orientation)>
+where
+    G: GridLike,
+    G::Item: PartialEq + Clone,
+{
+    let mut seen: Vec<Grid<G::Item>> = vec![];
+    let mut matches = vec![];
+
+    for (orientation, oriented) in orientations(pattern) {
+        if seen.contains(&oriented) {
+            continue;
+        }
+        matches.extend(
+            find_pattern(grid, &oriented)
+                .into_iter()
+                .map(|anchor| (anchor, orientation)),
+        );
+        seen.push(oriented);
+    }
+
+    matches
+}
@@ -0,0 +1,249 @@
+use glam::IVec2;
+use std::collections::HashSet;
+
+use super::{next_point, Connectivity, GridLike};
+
+/// The orthogonal neighbor offsets used to measure a region's edges.
+const ORTHOGONAL: [IVec2; 4] = [IVec2::NEG_Y, IVec2::X, IVec2::Y, IVec2::NEG_X];
+
+/// A connected group of cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    /// The index of the region in the order it was found.
+    pub label: usize,
+    pub cells: HashSet<IVec2>,
+}
+
+impl Region {
+    /// Returns `true` if the point belongs to the region.
+    pub fn contains(&self, point: &IVec2) -> bool {
+        self.cells.contains(point)
+    }
+
+    /// The number of cells in the region.
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// The number of cell edges on the boundary of the region, including the
+    /// edges around any holes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// // A ring of eight cells around a hole.
+    /// let grid = aoc::grid::locate(vec![
+    ///     vec!['A', 'A', 'A'],
+    ///     vec!['A', 'B', 'A'],
+    ///     vec!['A', 'A', 'A'],
+    /// ]);
+    ///
+    /// let regions = aoc::grid::regions(&grid, |a, b| a == b);
+    /// assert_eq!(regions[0].perimeter(), 16);
+    /// assert_eq!(regions[1].perimeter(), 4);
+    /// ```
+    pub fn perimeter(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|cell| {
+                ORTHOGONAL
+                    .iter()
+                    .filter(|offset| !self.contains(&(*cell + **offset)))
+                    .count()
+            })
+            .sum()
+    }
+
+    /// The number of corners on the boundary of the region, counting both
+    /// convex and concave corners.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// // An L shape has six corners.
+    /// let grid = aoc::grid::locate(vec![
+    ///     vec!['A', '.'],
+    ///     vec!['A', 'A'],
+    /// ]);
+    ///
+    /// let regions = aoc::grid::regions(&grid, |a, b| a == b);
+    /// assert_eq!(regions[0].corners(), 6);
+    /// ```
+    pub fn corners(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|cell| {
+                (0..4)
+                    .filter(|index| {
+                        let a = ORTHOGONAL[*index];
+                        let b = ORTHOGONAL[(*index + 1) % 4];
+                        let (has_a, has_b) =
+                            (self.contains(&(*cell + a)), self.contains(&(*cell + b)));
+                        let convex = !has_a && !has_b;
+                        let concave = has_a && has_b && !self.contains(&(*cell + a + b));
+                        convex || concave
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    /// The number of straight sides on the boundary of the region.
+    ///
+    /// Every side of a closed outline ends in a corner, so this is the same
+    /// as the number of corners.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let grid = aoc::grid::locate(vec![
+    ///     vec!['A', 'A', 'A', 'A'],
+    ///     vec!['B', 'B', 'C', 'D'],
+    /// ]);
+    ///
+    /// let regions = aoc::grid::regions(&grid, |a, b| a == b);
+    /// let sides = regions.iter().map(|region| region.sides()).collect::<Vec<_>>();
+    /// assert_eq!(sides, vec![4, 4, 4, 4]);
+    /// ```
+    pub fn sides(&self) -> usize {
+        self.corners()
+    }
+
+    /// Returns the top-left and bottom-right corners of the smallest
+    /// rectangle containing the region.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::IVec2;
+    ///
+    /// let grid = aoc::grid::locate(vec![
+    ///     vec!['.', 'A', '.'],
+    ///     vec!['A', 'A', '.'],
+    /// ]);
+    ///
+    /// let regions = aoc::grid::regions(&grid, |a, b| a == b);
+    /// assert_eq!(
+    ///     regions[1].bounding_box(),
+    ///     (IVec2::new(0, 0), IVec2::new(1, 1))
+    /// );
+    /// ```
+    pub fn bounding_box(&self) -> (IVec2, IVec2) {
+        let min = self.cells.iter().copied().reduce(IVec2::min);
+        let max = self.cells.iter().copied().reduce(IVec2::max);
+        (min.unwrap_or(IVec2::ZERO), max.unwrap_or(IVec2::ZERO))
+    }
+}
+
+/// Collect every cell reachable from `seed` by moving between adjacent cells
+/// that satisfy `fill`.
+///
+/// Returns an empty set if the seed itself is not in the grid or does not
+/// satisfy `fill`.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::grid::Connectivity;
+/// use glam::IVec2;
+///
+/// let grid = aoc::grid::locate(vec![
+///     vec!['.', '#', '.'],
+///     vec!['#', '.', '.'],
+/// ]);
+///
+/// let four = aoc::grid::flood_fill(&grid, IVec2::new(0, 0), Connectivity::Four, |c| *c == '.');
+/// assert_eq!(four.len(), 1);
+///
+/// let eight = aoc::grid::flood_fill(&grid, IVec2::new(0, 0), Connectivity::Eight, |c| *c == '.');
+/// assert_eq!(eight.len(), 4);
+/// ```
+pub fn flood_fill<G: GridLike>(
+    grid: &G,
+    seed: IVec2,
+    connectivity: Connectivity,
+    fill: impl Fn(&G::Item) -> bool,
+) -> HashSet<IVec2> {
+    let mut filled = HashSet::new();
+    if !grid.get(&seed).is_some_and(&fill) {
+        return filled;
+    }
+
+    let mut stack = vec![seed];
+    filled.insert(seed);
+    while let Some(point) = stack.pop() {
+        for direction in connectivity.directions() {
+            let next = next_point(&point, direction);
+            if grid.get(&next).is_some_and(&fill) && filled.insert(next) {
+                stack.push(next);
+            }
+        }
+    }
+
+    filled
+}
+
+/// Split the grid into orthogonally connected regions.
+///
+/// Each region grows from its first cell, taking in every neighbor for which
+/// `same` returns `true` when compared with the cell it was reached from.
+/// Every cell belongs to exactly one region, even if `same` is not transitive.
+///
+/// Regions are labelled in the order their top-left-most cell appears when
+/// reading the grid row by row.
+///
+/// # Examples
+///
+/// ```
+/// let grid = aoc::grid::locate(vec![
+///     vec!['A', 'A', 'B'],
+///     vec!['C', 'A', 'B'],
+///     vec!['C', 'C', 'A'],
+/// ]);
+///
+/// let regions = aoc::grid::regions(&grid, |a, b| a == b);
+/// let areas = regions.iter().map(|region| region.area()).collect::<Vec<_>>();
+/// assert_eq!(areas, vec![3, 2, 3, 1]);
+/// assert_eq!(regions[1].label, 1);
+///
+/// // Close enough values chain together into one region.
+/// let heights = aoc::grid::locate(vec![vec![1, 2, 3, 5]]);
+/// let regions = aoc::grid::regions(&heights, |a: &i32, b: &i32| (a - b).abs() <= 1);
+/// let areas = regions.iter().map(|region| region.area()).collect::<Vec<_>>();
+/// assert_eq!(areas, vec![3, 1]);
+/// ```
+pub fn regions<G: GridLike>(grid: &G, same: impl Fn(&G::Item, &G::Item) -> bool) -> Vec<Region> {
+    let mut cells = grid.cells().collect::<Vec<_>>();
+    cells.sort_by_key(|(point, _)| (point.y, point.x));
+
+    let mut seen = HashSet::new();
+    let mut regions = vec![];
+
+    for (start, _) in cells {
+        if !seen.insert(start) {
+            continue;
+        }
+
+        let mut region = HashSet::from([start]);
+        let mut stack = vec![start];
+        while let Some(point) = stack.pop() {
+            let Some(value) = grid.get(&point) else {
+                continue;
+            };
+            for direction in Connectivity::Four.directions() {
+                let next = next_point(&point, direction);
+                if grid.get(&next).is_some_and(|other| same(value, other)) && seen.insert(next) {
+                    region.insert(next);
+                    stack.push(next);
+                }
+            }
+        }
+
+        regions.push(Region {
+            label: regions.len(),
+            cells: region,
+        });
+    }
+
+    regions
+}
@@ -0,0 +1,293 @@
+use glam::IVec2;
+
+use super::{DenseGrid, Grid, GridLike};
+
+/// One of the eight symmetries of a rectangle: four rotations, optionally
+/// preceded by a reflection.
+///
+/// Rotations are clockwise as seen on screen, with `y` pointing down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    Identity,
+    RotateCw,
+    Rotate180,
+    RotateCcw,
+    /// Mirror left to right.
+    FlipHorizontal,
+    /// Mirror top to bottom.
+    FlipVertical,
+    /// Swap rows and columns, mirroring along the main diagonal.
+    Transpose,
+    /// Mirror along the anti-diagonal.
+    AntiTranspose,
+}
+
+impl Orientation {
+    /// Every orientation, starting with the identity.
+    pub const ALL: [Orientation; 8] = [
+        Orientation::Identity,
+        Orientation::RotateCw,
+        Orientation::Rotate180,
+        Orientation::RotateCcw,
+        Orientation::FlipHorizontal,
+        Orientation::FlipVertical,
+        Orientation::Transpose,
+        Orientation::AntiTranspose,
+    ];
+
+    /// Map a point of a grid whose top-left corner is the origin and whose
+    /// bottom-right corner is `max` onto the transformed grid, which also
+    /// starts at the origin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::grid::Orientation;
+    /// use glam::IVec2;
+    ///
+    /// let max = IVec2::new(2, 1);
+    /// assert_eq!(Orientation::RotateCw.apply(IVec2::new(0, 0), max), IVec2::new(1, 0));
+    /// assert_eq!(Orientation::RotateCcw.apply(IVec2::new(0, 0), max), IVec2::new(0, 2));
+    /// assert_eq!(Orientation::FlipHorizontal.apply(IVec2::new(0, 1), max), IVec2::new(2, 1));
+    /// ```
+    pub fn apply(self, point: IVec2, max: IVec2) -> IVec2 {
+        let IVec2 { x, y } = point;
+        match self {
+            Orientation::Identity => IVec2::new(x, y),
+            Orientation::RotateCw => IVec2::new(max.y - y, x),
+            Orientation::Rotate180 => IVec2::new(max.x - x, max.y - y),
+            Orientation::RotateCcw => IVec2::new(y, max.x - x),
+            Orientation::FlipHorizontal => IVec2::new(max.x - x, y),
+            Orientation::FlipVertical => IVec2::new(x, max.y - y),
+            Orientation::Transpose => IVec2::new(y, x),
+            Orientation::AntiTranspose => IVec2::new(max.y - y, max.x - x),
+        }
+    }
+
+    /// The orientation that undoes this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::grid::Orientation;
+    ///
+    /// assert_eq!(Orientation::RotateCw.inverse(), Orientation::RotateCcw);
+    /// assert_eq!(Orientation::FlipVertical.inverse(), Orientation::FlipVertical);
+    /// ```
+    pub fn inverse(self) -> Self {
+        match self {
+            Orientation::RotateCw => Orientation::RotateCcw,
+            Orientation::RotateCcw => Orientation::RotateCw,
+            other => other,
+        }
+    }
+
+    /// Returns `true` if the orientation swaps the width and height.
+    pub fn swaps_axes(self) -> bool {
+        matches!(
+            self,
+            Orientation::RotateCw
+                | Orientation::RotateCcw
+                | Orientation::Transpose
+                | Orientation::AntiTranspose
+        )
+    }
+}
+
+/// Returns a new grid transformed by the given orientation.
+///
+/// The grid is normalized first, so the result always has its top-left
+/// corner at the origin regardless of where the original grid started.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::grid::{Grid, Orientation};
+/// use glam::IVec2;
+///
+/// let mut grid = Grid::new();
+/// grid.insert(IVec2::new(5, 5), 1);
+/// grid.insert(IVec2::new(6, 5), 2);
+///
+/// let oriented = aoc::grid::orient(&grid, Orientation::Transpose);
+/// assert_eq!(oriented[&IVec2::new(0, 0)], 1);
+/// assert_eq!(oriented[&IVec2::new(0, 1)], 2);
+/// ```
+pub fn orient<G>(grid: &G, orientation: Orientation) -> Grid<G::Item>
+where
+    G: GridLike,
+    G::Item: Clone,
+{
+    let (top_left, bottom_right) = grid.boundaries();
+    let max = bottom_right - top_left;
+    grid.cells()
+        .map(|(point, value)| (orientation.apply(point - top_left, max), value.clone()))
+        .collect()
+}
+
+/// Returns a new grid rotated 90 degrees clockwise.
+///
+/// # Examples
+///
+/// ```
+/// // 1 2      3 1
+/// // 3 4  ->  4 2
+/// let grid = aoc::grid::locate(vec![vec![1, 2], vec![3, 4]]);
+///
+/// let rotated = aoc::grid::rotate_cw(&grid);
+/// assert_eq!(rotated, aoc::grid::locate(vec![vec![3, 1], vec![4, 2]]));
+/// ```
+pub fn rotate_cw<G>(grid: &G) -> Grid<G::Item>
+where
+    G: GridLike,
+    G::Item: Clone,
+{
+    orient(grid, Orientation::RotateCw)
+}
+
+/// Returns a new grid rotated 90 degrees counter-clockwise.
+///
+/// # Examples
+///
+/// ```
+/// // 1 2      2 4
+/// // 3 4  ->  1 3
+/// let grid = aoc::grid::locate(vec![vec![1, 2], vec![3, 4]]);
+///
+/// let rotated = aoc::grid::rotate_ccw(&grid);
+/// assert_eq!(rotated, aoc::grid::locate(vec![vec![2, 4], vec![1, 3]]));
+/// assert_eq!(aoc::grid::rotate_cw(&rotated), grid);
+/// ```
+pub fn rotate_ccw<G>(grid: &G) -> Grid<G::Item>
+where
+    G: GridLike,
+    G::Item: Clone,
+{
+    orient(grid, Orientation::RotateCcw)
+}
+
+/// Returns a new grid rotated 180 degrees.
+///
+/// # Examples
+///
+/// ```
+/// let grid = aoc::grid::locate(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+///
+/// let rotated = aoc::grid::rotate_180(&grid);
+/// assert_eq!(rotated, aoc::grid::locate(vec![vec![6, 5, 4], vec![3, 2, 1]]));
+/// ```
+pub fn rotate_180<G>(grid: &G) -> Grid<G::Item>
+where
+    G: GridLike,
+    G::Item: Clone,
+{
+    orient(grid, Orientation::Rotate180)
+}
+
+/// Returns a new grid mirrored left to right.
+///
+/// # Examples
+///
+/// ```
+/// let grid = aoc::grid::locate(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+///
+/// let flipped = aoc::grid::flip_horizontal(&grid);
+/// assert_eq!(flipped, aoc::grid::locate(vec![vec![3, 2, 1], vec![6, 5, 4]]));
+/// ```
+pub fn flip_horizontal<G>(grid: &G) -> Grid<G::Item>
+where
+    G: GridLike,
+    G::Item: Clone,
+{
+    orient(grid, Orientation::FlipHorizontal)
+}
+
+/// Returns a new grid mirrored top to bottom.
+///
+/// # Examples
+///
+/// ```
+/// let grid = aoc::grid::locate(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+///
+/// let flipped = aoc::grid::flip_vertical(&grid);
+/// assert_eq!(flipped, aoc::grid::locate(vec![vec![4, 5, 6], vec![1, 2, 3]]));
+/// ```
+pub fn flip_vertical<G>(grid: &G) -> Grid<G::Item>
+where
+    G: GridLike,
+    G::Item: Clone,
+{
+    orient(grid, Orientation::FlipVertical)
+}
+
+/// Iterate over all eight orientations of a grid, starting with the grid
+/// itself.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::grid::Orientation;
+/// use std::collections::HashSet;
+///
+/// let grid = aoc::grid::locate(vec![vec![1, 2], vec![3, 4]]);
+///
+/// let all = aoc::grid::orientations(&grid).collect::<Vec<_>>();
+/// assert_eq!(all.len(), 8);
+/// assert_eq!(all[0], (Orientation::Identity, grid.clone()));
+///
+/// // Every symmetry of an asymmetric grid is distinct.
+/// let distinct = all
+///     .iter()
+///     .map(|(_, grid)| aoc::grid::rows(grid).unwrap())
+///     .collect::<HashSet<_>>();
+/// assert_eq!(distinct.len(), 8);
+/// ```
+pub fn orientations<G>(grid: &G) -> impl Iterator<Item = (Orientation, Grid<G::Item>)> + '_
+where
+    G: GridLike,
+    G::Item: Clone,
+{
+    Orientation::ALL
+        .into_iter()
+        .map(|orientation| (orientation, orient(grid, orientation)))
+}
+
+impl<T: Clone> DenseGrid<T> {
+    /// Returns a new grid transformed by the given orientation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::grid::{DenseGrid, Orientation};
+    ///
+    /// let grid = DenseGrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    ///
+    /// let rotated = grid.orient(Orientation::RotateCw);
+    /// assert_eq!(rotated, DenseGrid::from_rows(vec![vec![4, 1], vec![5, 2], vec![6, 3]]).unwrap());
+    /// assert_eq!(rotated.orient(Orientation::RotateCw.inverse()), grid);
+    /// ```
+    pub fn orient(&self, orientation: Orientation) -> Self {
+        let (width, height) = if orientation.swaps_axes() {
+            (self.height(), self.width())
+        } else {
+            (self.width(), self.height())
+        };
+        let max = self.boundaries().1;
+
+        let mut cells = vec![None; self.len()];
+        for (point, value) in self.iter() {
+            let target = orientation.apply(point, max);
+            cells[target.y as usize * width + target.x as usize] = Some(value.clone());
+        }
+
+        Self::new(
+            width,
+            height,
+            cells
+                .into_iter()
+                .map(|cell| cell.expect("every cell is mapped"))
+                .collect(),
+        )
+        .expect("the dimensions are preserved")
+    }
+}
@@ -0,0 +1,248 @@
+use glam::IVec2;
+use std::collections::HashMap;
+
+use super::{next_point, Connectivity, Direction, GridLike};
+
+/// Split a point into the cell of the base grid it lands on and the tile copy
+/// it falls in, for a grid repeating every `size` cells from `top_left`.
+fn locate(top_left: IVec2, size: IVec2, point: &IVec2) -> (IVec2, IVec2) {
+    let relative = *point - top_left;
+    (
+        top_left + relative.rem_euclid(size),
+        relative.div_euclid(size),
+    )
+}
+
+/// The top-left corner and size of a grid's boundaries.
+fn extent<G: GridLike>(grid: &G) -> (IVec2, IVec2) {
+    let (top_left, bottom_right) = grid.boundaries();
+    (top_left, bottom_right - top_left + IVec2::ONE)
+}
+
+/// A view of a grid whose edges wrap around, so walking off one side comes
+/// back in on the opposite side.
+///
+/// Every point is taken modulo the size of the grid's boundaries, and the
+/// positions returned by [`WrappingGrid::travel`] and
+/// [`WrappingGrid::neighbors`] are always inside the grid.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::grid::{Direction, WrappingGrid};
+/// use glam::IVec2;
+///
+/// let grid = aoc::grid::locate(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+/// let wrapping = WrappingGrid::new(&grid);
+///
+/// assert_eq!(wrapping.get(&IVec2::new(-1, 0)), Some(&3));
+/// assert_eq!(wrapping.get(&IVec2::new(4, 3)), Some(&5));
+///
+/// let (next, value) = wrapping.travel(&IVec2::new(0, 0), Direction::North);
+/// assert_eq!(next, IVec2::new(0, 1));
+/// assert_eq!(value, Some(&4));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct WrappingGrid<'a, G> {
+    grid: &'a G,
+    top_left: IVec2,
+    size: IVec2,
+}
+
+impl<'a, G: GridLike> WrappingGrid<'a, G> {
+    pub fn new(grid: &'a G) -> Self {
+        let (top_left, size) = extent(grid);
+        Self {
+            grid,
+            top_left,
+            size,
+        }
+    }
+
+    /// The width and height of the repeating area.
+    pub fn size(&self) -> IVec2 {
+        self.size
+    }
+
+    /// Map any point onto the corresponding point inside the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::grid::WrappingGrid;
+    /// use glam::IVec2;
+    ///
+    /// let grid = aoc::grid::locate(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    /// let wrapping = WrappingGrid::new(&grid);
+    ///
+    /// assert_eq!(wrapping.wrap(&IVec2::new(-1, -1)), IVec2::new(2, 1));
+    /// assert_eq!(wrapping.wrap(&IVec2::new(7, 2)), IVec2::new(1, 0));
+    /// ```
+    pub fn wrap(&self, point: &IVec2) -> IVec2 {
+        locate(self.top_left, self.size, point).0
+    }
+
+    /// Get an optional reference to the value at the wrapped point.
+    pub fn get(&self, point: &IVec2) -> Option<&'a G::Item> {
+        self.grid.get(&self.wrap(point))
+    }
+
+    /// Move one step in the given direction, wrapping around the edges.
+    pub fn travel(&self, point: &IVec2, direction: Direction) -> (IVec2, Option<&'a G::Item>) {
+        let next = self.wrap(&next_point(point, &direction));
+        (next, self.grid.get(&next))
+    }
+
+    /// Iterate over the neighbors of a point, wrapping around the edges,
+    /// without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::grid::{Connectivity, Direction, WrappingGrid};
+    /// use glam::IVec2;
+    ///
+    /// let grid = aoc::grid::locate(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    /// let wrapping = WrappingGrid::new(&grid);
+    ///
+    /// let neighbors = wrapping
+    ///     .adjacent(&IVec2::new(0, 0), Connectivity::Four)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(neighbors[3], (Direction::West, IVec2::new(2, 0), &3));
+    /// ```
+    pub fn adjacent(
+        &self,
+        point: &IVec2,
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = (Direction, IVec2, &'a G::Item)> + '_ {
+        let point = *point;
+        connectivity
+            .directions()
+            .iter()
+            .filter_map(move |direction| {
+                let next = self.wrap(&next_point(&point, direction));
+                Some((*direction, next, self.grid.get(&next)?))
+            })
+    }
+
+    /// Returns a HashMap of the neighbors of a point, wrapping around the
+    /// edges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::grid::{Connectivity, Direction, WrappingGrid};
+    /// use glam::IVec2;
+    ///
+    /// let grid = aoc::grid::locate(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    /// let wrapping = WrappingGrid::new(&grid);
+    ///
+    /// let neighbors = wrapping.neighbors(&IVec2::new(0, 0), Connectivity::Eight);
+    /// assert_eq!(neighbors.len(), 8);
+    /// assert_eq!(neighbors[&Direction::NorthWest], (IVec2::new(2, 1), &6));
+    /// ```
+    pub fn neighbors(
+        &self,
+        point: &IVec2,
+        connectivity: Connectivity,
+    ) -> HashMap<Direction, (IVec2, &'a G::Item)> {
+        self.adjacent(point, connectivity)
+            .map(|(direction, next, value)| (direction, (next, value)))
+            .collect()
+    }
+}
+
+impl<G: GridLike> GridLike for WrappingGrid<'_, G> {
+    type Item = G::Item;
+
+    fn get(&self, point: &IVec2) -> Option<&G::Item> {
+        WrappingGrid::get(self, point)
+    }
+
+    fn cells(&self) -> impl Iterator<Item = (IVec2, &G::Item)> {
+        self.grid.cells()
+    }
+
+    fn boundaries(&self) -> (IVec2, IVec2) {
+        self.grid.boundaries()
+    }
+}
+
+/// A view of a grid repeated infinitely in every direction.
+///
+/// Unlike a [`WrappingGrid`], points keep their unbounded coordinates, and
+/// each one can be traced back to the cell of the underlying grid and the
+/// copy of the grid it falls in.  The copy containing the original grid is
+/// tile `(0, 0)`.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::grid::TiledGrid;
+/// use glam::IVec2;
+///
+/// let grid = aoc::grid::locate(vec![vec!['.', '.'], vec!['#', '.']]);
+/// let tiled = TiledGrid::new(&grid);
+///
+/// assert_eq!(tiled.locate(&IVec2::new(5, -1)), (IVec2::new(1, 1), IVec2::new(2, -1)));
+/// assert_eq!(tiled.get(&IVec2::new(5, -1)), Some(&'.'));
+///
+/// // Searches over a tiled grid can leave the original copy.
+/// let (steps, _) =
+///     aoc::search::bfs_grid(&tiled, IVec2::new(0, 0), IVec2::new(4, 0), |c| *c == '.').unwrap();
+/// assert_eq!(steps, 4);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TiledGrid<'a, G> {
+    grid: &'a G,
+    top_left: IVec2,
+    size: IVec2,
+}
+
+impl<'a, G: GridLike> TiledGrid<'a, G> {
+    pub fn new(grid: &'a G) -> Self {
+        let (top_left, size) = extent(grid);
+        Self {
+            grid,
+            top_left,
+            size,
+        }
+    }
+
+    /// The width and height of a single tile.
+    pub fn size(&self) -> IVec2 {
+        self.size
+    }
+
+    /// Returns the cell of the underlying grid a point maps to, and the tile
+    /// copy it falls in.
+    pub fn locate(&self, point: &IVec2) -> (IVec2, IVec2) {
+        locate(self.top_left, self.size, point)
+    }
+
+    /// The tile copy a point falls in.
+    pub fn tile(&self, point: &IVec2) -> IVec2 {
+        self.locate(point).1
+    }
+
+    /// Get an optional reference to the value of the underlying cell.
+    pub fn get(&self, point: &IVec2) -> Option<&'a G::Item> {
+        self.grid.get(&self.locate(point).0)
+    }
+}
+
+impl<G: GridLike> GridLike for TiledGrid<'_, G> {
+    type Item = G::Item;
+
+    fn get(&self, point: &IVec2) -> Option<&G::Item> {
+        TiledGrid::get(self, point)
+    }
+
+    fn cells(&self) -> impl Iterator<Item = (IVec2, &G::Item)> {
+        self.grid.cells()
+    }
+
+    fn boundaries(&self) -> (IVec2, IVec2) {
+        self.grid.boundaries()
+    }
+}
@@ -0,0 +1,244 @@
+use glam::{IVec2, IVec3};
+use std::collections::{HashMap, HashSet};
+
+use crate::grid::Grid;
+
+pub type Grid3<T> = HashMap<IVec3, T>;
+
+/// The six neighbors sharing a face with a cell.
+const FACES: [IVec3; 6] = [
+    IVec3::NEG_X,
+    IVec3::X,
+    IVec3::NEG_Y,
+    IVec3::Y,
+    IVec3::NEG_Z,
+    IVec3::Z,
+];
+
+/// Every neighbor sharing a face, edge or corner with a cell.
+const ALL: [IVec3; 26] = {
+    let mut offsets = [IVec3::ZERO; 26];
+    let mut index = 0;
+    let mut i = 0;
+    while i < 27 {
+        let offset = IVec3::new(i % 3 - 1, i / 3 % 3 - 1, i / 9 - 1);
+        if i != 13 {
+            offsets[index] = offset;
+            index += 1;
+        }
+        i += 1;
+    }
+    offsets
+};
+
+/// Which neighbors of a cell count as adjacent to it.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Connectivity {
+    /// Only the neighbors sharing a face.
+    Six,
+    /// The neighbors sharing a face, an edge or a corner.
+    TwentySix,
+}
+
+impl Connectivity {
+    /// The offsets of every adjacent neighbor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::grid3::Connectivity;
+    /// use glam::IVec3;
+    ///
+    /// assert_eq!(Connectivity::Six.offsets().len(), 6);
+    /// assert_eq!(Connectivity::TwentySix.offsets().len(), 26);
+    /// assert!(!Connectivity::TwentySix.offsets().contains(&IVec3::ZERO));
+    /// ```
+    pub fn offsets(self) -> &'static [IVec3] {
+        match self {
+            Connectivity::Six => &FACES,
+            Connectivity::TwentySix => &ALL,
+        }
+    }
+}
+
+/// Iterate over the neighbors of a point that are in the grid, without
+/// allocating.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::grid3::{Connectivity, Grid3};
+/// use glam::IVec3;
+///
+/// let mut grid = Grid3::new();
+/// grid.insert(IVec3::new(0, 0, 0), 'a');
+/// grid.insert(IVec3::new(0, 0, 1), 'b');
+/// grid.insert(IVec3::new(1, 1, 1), 'c');
+///
+/// let six = aoc::grid3::adjacent(&grid, &IVec3::ZERO, Connectivity::Six).collect::<Vec<_>>();
+/// assert_eq!(six, vec![(IVec3::new(0, 0, 1), &'b')]);
+///
+/// let all = aoc::grid3::adjacent(&grid, &IVec3::ZERO, Connectivity::TwentySix);
+/// assert_eq!(all.count(), 2);
+/// ```
+pub fn adjacent<'a, T>(
+    grid: &'a Grid3<T>,
+    point: &IVec3,
+    connectivity: Connectivity,
+) -> impl Iterator<Item = (IVec3, &'a T)> + 'a {
+    let point = *point;
+    connectivity.offsets().iter().filter_map(move |offset| {
+        let next = point + *offset;
+        grid.get(&next).map(|value| (next, value))
+    })
+}
+
+/// Returns the minimum and maximum corners of the box containing every
+/// point in the grid.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::grid3::Grid3;
+/// use glam::IVec3;
+///
+/// let mut grid = Grid3::new();
+/// grid.insert(IVec3::new(1, -2, 3), ());
+/// grid.insert(IVec3::new(-1, 4, 0), ());
+///
+/// assert_eq!(
+///     aoc::grid3::boundaries(&grid),
+///     (IVec3::new(-1, -2, 0), IVec3::new(1, 4, 3))
+/// );
+/// ```
+pub fn boundaries<T>(grid: &Grid3<T>) -> (IVec3, IVec3) {
+    let min = grid.keys().copied().reduce(IVec3::min);
+    let max = grid.keys().copied().reduce(IVec3::max);
+    (min.unwrap_or(IVec3::ZERO), max.unwrap_or(IVec3::ZERO))
+}
+
+/// The number of cell faces not touching another cell, including the faces
+/// around any enclosed cavities.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::grid3::Grid3;
+/// use glam::IVec3;
+///
+/// let grid = Grid3::from([(IVec3::new(1, 1, 1), ()), (IVec3::new(2, 1, 1), ())]);
+/// assert_eq!(aoc::grid3::surface_area(&grid), 10);
+/// ```
+pub fn surface_area<T>(grid: &Grid3<T>) -> usize {
+    grid.keys()
+        .map(|point| {
+            FACES
+                .iter()
+                .filter(|offset| !grid.contains_key(&(*point + **offset)))
+                .count()
+        })
+        .sum()
+}
+
+/// Flood fill the empty space around the grid, starting from outside its
+/// boundaries and moving through faces only.
+///
+/// The fill is limited to the grid's boundaries grown by one in every
+/// direction, so the result includes a shell of empty cells around the grid
+/// but none of the cavities enclosed by it.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::grid3::Grid3;
+/// use glam::IVec3;
+///
+/// // A 3x3x3 cube with a hollow center.
+/// let mut grid = Grid3::new();
+/// for x in 0..3 {
+///     for y in 0..3 {
+///         for z in 0..3 {
+///             grid.insert(IVec3::new(x, y, z), ());
+///         }
+///     }
+/// }
+/// grid.remove(&IVec3::ONE);
+///
+/// let exterior = aoc::grid3::exterior(&grid);
+/// assert!(!exterior.contains(&IVec3::ONE));
+/// assert!(exterior.contains(&IVec3::new(-1, -1, -1)));
+/// assert_eq!(exterior.len(), 5 * 5 * 5 - 27);
+/// ```
+pub fn exterior<T>(grid: &Grid3<T>) -> HashSet<IVec3> {
+    let (min, max) = boundaries(grid);
+    let (min, max) = (min - IVec3::ONE, max + IVec3::ONE);
+    let inside = |point: &IVec3| point.cmpge(min).all() && point.cmple(max).all();
+
+    let mut filled = HashSet::from([min]);
+    let mut stack = vec![min];
+    while let Some(point) = stack.pop() {
+        for offset in FACES {
+            let next = point + offset;
+            if inside(&next) && !grid.contains_key(&next) && filled.insert(next) {
+                stack.push(next);
+            }
+        }
+    }
+
+    filled
+}
+
+/// The number of cell faces that can be reached from outside the grid,
+/// ignoring the faces around enclosed cavities.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::grid3::Grid3;
+/// use glam::IVec3;
+///
+/// let mut grid = Grid3::new();
+/// for x in 0..3 {
+///     for y in 0..3 {
+///         for z in 0..3 {
+///             grid.insert(IVec3::new(x, y, z), ());
+///         }
+///     }
+/// }
+/// grid.remove(&IVec3::ONE);
+///
+/// assert_eq!(aoc::grid3::surface_area(&grid), 54 + 6);
+/// assert_eq!(aoc::grid3::exterior_surface_area(&grid), 54);
+/// ```
+pub fn exterior_surface_area<T>(grid: &Grid3<T>) -> usize {
+    let exterior = exterior(grid);
+    grid.keys()
+        .map(|point| {
+            FACES
+                .iter()
+                .filter(|offset| exterior.contains(&(*point + **offset)))
+                .count()
+        })
+        .sum()
+}
+
+/// Returns the 2D grid of every cell at the given z-level.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::grid3::Grid3;
+/// use glam::{IVec2, IVec3};
+///
+/// let grid = Grid3::from([(IVec3::new(1, 2, 0), 'a'), (IVec3::new(3, 4, 1), 'b')]);
+///
+/// let slice = aoc::grid3::slice_z(&grid, 1);
+/// assert_eq!(slice.len(), 1);
+/// assert_eq!(slice[&IVec2::new(3, 4)], 'b');
+/// ```
+pub fn slice_z<T: Clone>(grid: &Grid3<T>, z: i32) -> Grid<T> {
+    grid.iter()
+        .filter(|(point, _)| point.z == z)
+        .map(|(point, value)| (IVec2::new(point.x, point.y), value.clone()))
+        .collect()
+}
@@ -0,0 +1,383 @@
+use glam::{IVec2, IVec3};
+use std::collections::HashMap;
+use std::ops::{Add, Mul, Sub};
+use thiserror::Error;
+
+/// A position on a hexagonal grid in axial coordinates.
+///
+/// The third cube coordinate is implied by `q + r + s == 0`.  Positions are
+/// independent of how the hexagons are drawn, so the same `Hex` can be used
+/// with either [`Layout`].
+///
+/// # Examples
+///
+/// ```
+/// use aoc::hex::{Hex, Layout};
+///
+/// let path = Layout::Flat.parse_path("ne,ne,s,s").unwrap();
+/// let end = path.into_iter().fold(Hex::ORIGIN, |hex, step| hex + step);
+/// assert_eq!(end, Hex::new(2, 0));
+/// assert_eq!(Hex::ORIGIN.distance(end), 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Hex {
+    pub q: i32,
+    pub r: i32,
+}
+
+/// How hexagons are drawn, which decides the names of the six directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Layout {
+    /// Flat sides on top and bottom, with neighbors `n`, `ne`, `se`, `s`,
+    /// `sw` and `nw`.
+    Flat,
+    /// A point on top and bottom, with neighbors `e`, `se`, `sw`, `w`, `nw`
+    /// and `ne`.
+    Pointy,
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum HexParseError {
+    #[error("invalid hex direction {token:?} at column {column}")]
+    InvalidDirection { token: String, column: usize },
+}
+
+impl Hex {
+    pub const ORIGIN: Hex = Hex::new(0, 0);
+
+    /// The six unit steps, clockwise as drawn with `y` pointing down.
+    pub const DIRECTIONS: [Hex; 6] = [
+        Hex::new(1, 0),
+        Hex::new(0, 1),
+        Hex::new(-1, 1),
+        Hex::new(-1, 0),
+        Hex::new(0, -1),
+        Hex::new(1, -1),
+    ];
+
+    pub const fn new(q: i32, r: i32) -> Self {
+        Self { q, r }
+    }
+
+    /// The implied third cube coordinate.
+    pub fn s(self) -> i32 {
+        -self.q - self.r
+    }
+
+    /// Convert to cube coordinates `(q, r, s)`.
+    pub fn to_cube(self) -> IVec3 {
+        IVec3::new(self.q, self.r, self.s())
+    }
+
+    /// Convert from cube coordinates, or `None` if they do not sum to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::hex::Hex;
+    /// use glam::IVec3;
+    ///
+    /// let hex = Hex::new(2, -3);
+    /// assert_eq!(hex.to_cube(), IVec3::new(2, -3, 1));
+    /// assert_eq!(Hex::from_cube(hex.to_cube()), Some(hex));
+    /// assert_eq!(Hex::from_cube(IVec3::new(1, 1, 1)), None);
+    /// ```
+    pub fn from_cube(cube: IVec3) -> Option<Self> {
+        (cube.element_sum() == 0).then_some(Self::new(cube.x, cube.y))
+    }
+
+    /// Convert to offset coordinates, where odd rows of pointy hexes are
+    /// shifted right and odd columns of flat hexes are shifted down.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::hex::{Hex, Layout};
+    /// use glam::IVec2;
+    ///
+    /// let hex = Hex::new(-1, 3);
+    /// assert_eq!(hex.to_offset(Layout::Pointy), IVec2::new(0, 3));
+    /// assert_eq!(hex.to_offset(Layout::Flat), IVec2::new(-1, 2));
+    /// assert_eq!(Hex::from_offset(IVec2::new(0, 3), Layout::Pointy), hex);
+    /// assert_eq!(Hex::from_offset(IVec2::new(-1, 2), Layout::Flat), hex);
+    /// ```
+    pub fn to_offset(self, layout: Layout) -> IVec2 {
+        match layout {
+            Layout::Pointy => IVec2::new(self.q + (self.r - (self.r & 1)) / 2, self.r),
+            Layout::Flat => IVec2::new(self.q, self.r + (self.q - (self.q & 1)) / 2),
+        }
+    }
+
+    /// Convert from offset coordinates, the inverse of [`Hex::to_offset`].
+    pub fn from_offset(offset: IVec2, layout: Layout) -> Self {
+        match layout {
+            Layout::Pointy => Self::new(offset.x - (offset.y - (offset.y & 1)) / 2, offset.y),
+            Layout::Flat => Self::new(offset.x, offset.y - (offset.x - (offset.x & 1)) / 2),
+        }
+    }
+
+    /// Iterate over the six neighbors, clockwise from [`Hex::DIRECTIONS`].
+    pub fn neighbors(self) -> impl Iterator<Item = Hex> {
+        Self::DIRECTIONS.into_iter().map(move |step| self + step)
+    }
+
+    /// The number of steps between two hexes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::hex::Hex;
+    ///
+    /// assert_eq!(Hex::new(0, 0).distance(Hex::new(3, -1)), 3);
+    /// assert_eq!(Hex::new(-2, 0).distance(Hex::new(2, -2)), 4);
+    /// ```
+    pub fn distance(self, other: Hex) -> u32 {
+        let difference = (self - other).to_cube().abs();
+        difference.max_element() as u32
+    }
+
+    /// Rotate 60 degrees clockwise around the origin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::hex::Hex;
+    ///
+    /// let hex = Hex::new(2, -1);
+    /// assert_eq!(hex.rotate_cw(), Hex::new(1, 1));
+    /// assert_eq!(hex.rotate_cw().rotate_ccw(), hex);
+    /// assert_eq!(Hex::DIRECTIONS[0].rotate_cw(), Hex::DIRECTIONS[1]);
+    /// ```
+    pub fn rotate_cw(self) -> Self {
+        Self::new(-self.r, -self.s())
+    }
+
+    /// Rotate 60 degrees counter-clockwise around the origin.
+    pub fn rotate_ccw(self) -> Self {
+        Self::new(-self.s(), -self.q)
+    }
+
+    /// Rotate around `center` by the given number of 60 degree steps, where
+    /// positive steps turn clockwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::hex::Hex;
+    ///
+    /// let center = Hex::new(1, 1);
+    /// let hex = Hex::new(2, 1);
+    /// assert_eq!(hex.rotate_around(center, 3), Hex::new(0, 1));
+    /// assert_eq!(hex.rotate_around(center, -1), hex.rotate_around(center, 5));
+    /// ```
+    pub fn rotate_around(self, center: Hex, steps: i32) -> Self {
+        let relative = (0..steps.rem_euclid(6)).fold(self - center, |hex, _| hex.rotate_cw());
+        center + relative
+    }
+
+    /// Iterate over the hexes exactly `radius` steps away, clockwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::hex::Hex;
+    ///
+    /// let center = Hex::new(3, -2);
+    /// assert_eq!(center.ring(0).collect::<Vec<_>>(), vec![center]);
+    /// assert_eq!(center.ring(2).count(), 12);
+    /// assert!(center.ring(2).all(|hex| hex.distance(center) == 2));
+    /// ```
+    pub fn ring(self, radius: u32) -> impl Iterator<Item = Hex> {
+        let radius = radius as i32;
+        let start = self + Self::DIRECTIONS[4] * radius;
+        let steps = if radius == 0 { 1 } else { 6 * radius };
+
+        (0..steps).scan(start, move |hex, index| {
+            let current = *hex;
+            if radius > 0 {
+                *hex = *hex + Self::DIRECTIONS[(index / radius) as usize];
+            }
+            Some(current)
+        })
+    }
+
+    /// Iterate over every hex within `radius` steps, ring by ring starting
+    /// from the center.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::hex::Hex;
+    ///
+    /// let spiral = Hex::ORIGIN.spiral(2).collect::<Vec<_>>();
+    /// assert_eq!(spiral.len(), 19);
+    /// assert_eq!(spiral[0], Hex::ORIGIN);
+    /// ```
+    pub fn spiral(self, radius: u32) -> impl Iterator<Item = Hex> {
+        (0..=radius).flat_map(move |ring| self.ring(ring))
+    }
+}
+
+impl Add for Hex {
+    type Output = Hex;
+
+    fn add(self, other: Hex) -> Hex {
+        Hex::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl Sub for Hex {
+    type Output = Hex;
+
+    fn sub(self, other: Hex) -> Hex {
+        Hex::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl Mul<i32> for Hex {
+    type Output = Hex;
+
+    fn mul(self, scale: i32) -> Hex {
+        Hex::new(self.q * scale, self.r * scale)
+    }
+}
+
+impl Layout {
+    /// The names of the six directions, in the same order as
+    /// [`Hex::DIRECTIONS`].
+    fn names(self) -> [&'static str; 6] {
+        match self {
+            Layout::Flat => ["se", "s", "sw", "nw", "n", "ne"],
+            Layout::Pointy => ["e", "se", "sw", "w", "nw", "ne"],
+        }
+    }
+
+    /// Parse a single direction name into its unit step.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::hex::{Hex, Layout};
+    ///
+    /// assert_eq!(Layout::Flat.parse_direction("n"), Some(Hex::new(0, -1)));
+    /// assert_eq!(Layout::Pointy.parse_direction("NW"), Some(Hex::new(0, -1)));
+    /// assert_eq!(Layout::Pointy.parse_direction("n"), None);
+    /// ```
+    pub fn parse_direction(self, name: &str) -> Option<Hex> {
+        let name = name.to_ascii_lowercase();
+        self.names()
+            .iter()
+            .position(|candidate| *candidate == name)
+            .map(|index| Hex::DIRECTIONS[index])
+    }
+
+    /// The name of a unit step, or `None` if it is not one.
+    pub fn direction_name(self, step: Hex) -> Option<&'static str> {
+        Hex::DIRECTIONS
+            .iter()
+            .position(|direction| *direction == step)
+            .map(|index| self.names()[index])
+    }
+
+    /// Parse a path of direction names into unit steps.
+    ///
+    /// Names may be separated by commas or whitespace, or run together as in
+    /// `esenee`, in which case two letter names take precedence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::hex::{Hex, HexParseError, Layout};
+    ///
+    /// let path = Layout::Pointy.parse_path("esenee").unwrap();
+    /// assert_eq!(path.len(), 4);
+    /// assert_eq!(Layout::Pointy.direction_name(path[1]), Some("se"));
+    ///
+    /// let error = Layout::Flat.parse_path("n, e").unwrap_err();
+    /// assert_eq!(
+    ///     error,
+    ///     HexParseError::InvalidDirection { token: "e".to_string(), column: 4 }
+    /// );
+    /// ```
+    pub fn parse_path(self, input: &str) -> Result<Vec<Hex>, HexParseError> {
+        let mut path = vec![];
+        let mut column = 0;
+
+        while column < input.len() {
+            let rest = &input[column..];
+            let Some(character) = rest.chars().next() else {
+                break;
+            };
+            if character == ',' || character.is_whitespace() {
+                column += character.len_utf8();
+                continue;
+            }
+
+            let (step, length) = rest
+                .get(..2)
+                .and_then(|pair| Some((self.parse_direction(pair)?, 2)))
+                .or_else(|| Some((self.parse_direction(rest.get(..1)?)?, 1)))
+                .ok_or_else(|| HexParseError::InvalidDirection {
+                    token: rest
+                        .split(|c: char| c == ',' || c.is_whitespace())
+                        .next()
+                        .unwrap_or(rest)
+                        .to_string(),
+                    column: column + 1,
+                })?;
+
+            path.push(step);
+            column += length;
+        }
+
+        Ok(path)
+    }
+}
+
+/// Render hexes as text, one character per hex, with empty positions drawn
+/// as spaces.
+///
+/// Pointy hexes are drawn in rows with every other row indented by one
+/// column, and flat hexes in columns with every other column shifted down
+/// by one row.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::hex::{Hex, Layout};
+/// use std::collections::HashMap;
+///
+/// let cells = Hex::ORIGIN
+///     .spiral(1)
+///     .map(|hex| (hex, if hex == Hex::ORIGIN { '@' } else { '.' }))
+///     .collect::<HashMap<_, _>>();
+///
+/// assert_eq!(aoc::hex::render(&cells, Layout::Pointy), " . .\n. @ .\n . .\n");
+/// assert_eq!(aoc::hex::render(&cells, Layout::Flat), "  .\n.   .\n  @\n.   .\n  .\n");
+/// ```
+pub fn render(cells: &HashMap<Hex, char>, layout: Layout) -> String {
+    // Doubled coordinates keep neighbors evenly spaced on a text grid.
+    let position = |hex: &Hex| match layout {
+        Layout::Pointy => IVec2::new(2 * hex.q + hex.r, hex.r),
+        Layout::Flat => IVec2::new(2 * hex.q, 2 * hex.r + hex.q),
+    };
+
+    let positions = cells
+        .iter()
+        .map(|(hex, character)| (position(hex), *character))
+        .collect::<HashMap<_, _>>();
+    let Some(min) = positions.keys().copied().reduce(IVec2::min) else {
+        return String::new();
+    };
+    let max = positions.keys().copied().reduce(IVec2::max).unwrap_or(min);
+
+    let mut output = String::new();
+    for y in min.y..=max.y {
+        let line = (min.x..=max.x)
+            .map(|x| positions.get(&IVec2::new(x, y)).copied().unwrap_or(' '))
+            .collect::<String>();
+        output.push_str(line.trim_end());
+        output.push('\n');
+    }
+    output
+}
@@ -1,2 +1,12 @@
+pub mod cycle;
 pub mod grid;
+pub mod grid3;
+pub mod hex;
 pub mod math;
+pub mod parse;
+pub mod search;
+pub mod viz;
+
+mod solution;
+
+pub use solution::{Answer, Solution};
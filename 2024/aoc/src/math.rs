@@ -1,28 +1,162 @@
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, RangeInclusive, Rem, Sub};
+use thiserror::Error;
+
+mod polynomial;
+mod rational;
+
+pub use polynomial::{extrapolate, Polynomial};
+pub use rational::Rational;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum MathError {
+    #[error("cannot take the least common multiple of no numbers")]
+    Empty,
+
+    #[error("the least common multiple overflows")]
+    Overflow,
+}
+
+/// The primitive integer operations the functions in this module need.
+///
+/// Implemented for every signed and unsigned primitive integer.
+pub trait Integer:
+    Copy
+    + Ord
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const TWO: Self;
+
+    /// The absolute value, or `None` for the minimum of a signed type, whose
+    /// absolute value does not fit.  Unsigned integers are returned as is.
+    fn checked_abs(self) -> Option<Self>;
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_div(self, other: Self) -> Option<Self>;
+    fn checked_neg(self) -> Option<Self>;
+    fn rem_euclid(self, other: Self) -> Self;
+    /// The remainder, which is zero rather than an overflow for the minimum
+    /// of a signed type divided by `-1`.
+    fn wrapping_rem(self, other: Self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($checked_abs:expr => $($t:ty),*) => {$(
+        impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const TWO: Self = 2;
+
+            fn checked_abs(self) -> Option<Self> {
+                $checked_abs(self)
+            }
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$t>::checked_sub(self, other)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+
+            fn checked_div(self, other: Self) -> Option<Self> {
+                <$t>::checked_div(self, other)
+            }
+
+            fn checked_neg(self) -> Option<Self> {
+                <$t>::checked_neg(self)
+            }
+
+            fn rem_euclid(self, other: Self) -> Self {
+                <$t>::rem_euclid(self, other)
+            }
+
+            fn wrapping_rem(self, other: Self) -> Self {
+                <$t>::wrapping_rem(self, other)
+            }
+        }
+    )*};
+}
+
+impl_integer!(Some => u8, u16, u32, u64, u128, usize);
+impl_integer!(Self::checked_abs => i8, i16, i32, i64, i128, isize);
+
 /// Least Common Multiple
 ///
-/// Calculate the least common multiple for a slice of numbers.
+/// Calculate the least common multiple for a slice of numbers, failing if
+/// the slice is empty or the result does not fit in `T`.
 ///
 /// # Example:
 ///
 /// ```
-/// use aoc::math::lcm;
+/// use aoc::math::{lcm, MathError};
 ///
 /// let nums = [2, 3, 4, 5, 6];
 /// let result = lcm(&nums);
-/// assert_eq!(60, result);
+/// assert_eq!(Ok(60), result);
+///
+/// assert_eq!(lcm::<u64>(&[]), Err(MathError::Empty));
+/// assert_eq!(lcm(&[u8::MAX, 2]), Err(MathError::Overflow));
+/// assert_eq!(lcm(&[i64::MIN, 2]), Err(MathError::Overflow));
+/// assert_eq!(lcm(&[i64::MIN]), Err(MathError::Overflow));
+/// ```
+pub fn lcm<T: Integer>(input: &[T]) -> Result<T, MathError> {
+    let (first, rest) = input.split_first().ok_or(MathError::Empty)?;
+    let first = first.checked_abs().ok_or(MathError::Overflow)?;
+    rest.iter().try_fold(first, |result, n| {
+        checked_lcm(result, *n).ok_or(MathError::Overflow)
+    })
+}
+
+/// Least Common Multiple
+///
+/// Calculate the least common multiple for two numbers, or `None` if it does
+/// not fit in `T`.
+///
+/// # Example:
+///
 /// ```
-pub fn lcm(input: &[i64]) -> i64 {
-    if input.len() == 1 {
-        return input[0];
+/// use aoc::math::checked_lcm;
+///
+/// assert_eq!(checked_lcm(4, 6), Some(12));
+/// assert_eq!(checked_lcm(-4, 6), Some(12));
+/// assert_eq!(checked_lcm(0, 6), Some(0));
+/// assert_eq!(checked_lcm(u64::MAX, u64::MAX - 1), None);
+/// assert_eq!(checked_lcm(i64::MIN, 2), None);
+/// assert_eq!(checked_lcm(i64::MIN / 2, 2), Some(i64::MAX / 2 + 1));
+/// ```
+pub fn checked_lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
     }
-    let a = input[0];
-    let b = lcm(&input[1..]);
-    a * b / gcd(a, b)
+    // Dividing first keeps the intermediate value no larger than the result.
+    (a / checked_gcd(a, b)?)
+        .checked_abs()?
+        .checked_mul(b.checked_abs()?)
 }
 
 /// Greatest Common Divisor
 ///
-/// Calculate the greatest common divisor for two numbers.
+/// Calculate the greatest common divisor for two numbers.  The result is
+/// never negative.
+///
+/// # Panics
+///
+/// Panics if the result does not fit in `T`, which only happens when both
+/// numbers are zero or the minimum of a signed type.  Use [`checked_gcd`] to
+/// handle that case.
 ///
 /// # Example:
 ///
@@ -31,10 +165,380 @@ pub fn lcm(input: &[i64]) -> i64 {
 ///
 /// let result = gcd(12, 18);
 /// assert_eq!(6, result);
+/// assert_eq!(gcd(-12_i32, 18), 6);
+/// assert_eq!(gcd(0_u8, 7), 7);
+/// assert_eq!(gcd(i64::MIN, 6), 2);
+/// ```
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    checked_gcd(a, b).expect("the greatest common divisor overflows")
+}
+
+/// Greatest Common Divisor
+///
+/// Calculate the greatest common divisor for two numbers, or `None` if it
+/// does not fit in `T`.
+///
+/// # Example:
+///
+/// ```
+/// use aoc::math::checked_gcd;
+///
+/// assert_eq!(checked_gcd(-12, 18), Some(6));
+/// assert_eq!(checked_gcd(i64::MIN, -1), Some(1));
+/// assert_eq!(checked_gcd(i64::MIN, 0), None);
+/// assert_eq!(checked_gcd(i64::MIN, i64::MIN), None);
+/// ```
+pub fn checked_gcd<T: Integer>(a: T, b: T) -> Option<T> {
+    // Working on the signed values avoids taking the absolute value of the
+    // minimum until the very end, when it can only be the result itself.
+    let (mut a, mut b) = (a, b);
+    while b != T::ZERO {
+        (a, b) = (b, a.wrapping_rem(b));
+    }
+    a.checked_abs()
+}
+
+/// Extended Euclidean Algorithm
+///
+/// Calculate `(g, x, y)` where `g` is the greatest common divisor of `a` and
+/// `b`, and `a * x + b * y == g`.  Returns `None` if a value does not fit in
+/// `T`, which can only happen when an input is the minimum of the type.
+///
+/// # Example:
+///
+/// ```
+/// use aoc::math::extended_gcd;
+///
+/// let (g, x, y) = extended_gcd(240, 46).unwrap();
+/// assert_eq!(g, 2);
+/// assert_eq!(240 * x + 46 * y, 2);
+///
+/// assert_eq!(extended_gcd(-4, 6), Some((2, 1, 1)));
+/// assert_eq!(extended_gcd(i64::MIN, 2), Some((2, 0, 1)));
+/// assert_eq!(extended_gcd(i64::MIN, 0), None);
+/// ```
+pub fn extended_gcd<T: Integer + Neg<Output = T>>(a: T, b: T) -> Option<(T, T, T)> {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+
+    while r != T::ZERO {
+        let quotient = old_r.checked_div(r)?;
+        (old_r, r) = (r, old_r.checked_sub(quotient.checked_mul(r)?)?);
+        (old_x, x) = (x, old_x.checked_sub(quotient.checked_mul(x)?)?);
+        (old_y, y) = (y, old_y.checked_sub(quotient.checked_mul(y)?)?);
+    }
+
+    if old_r < T::ZERO {
+        Some((
+            old_r.checked_neg()?,
+            old_x.checked_neg()?,
+            old_y.checked_neg()?,
+        ))
+    } else {
+        Some((old_r, old_x, old_y))
+    }
+}
+
+/// Modular Inverse
+///
+/// Calculate `x` in `0..modulus` such that `a * x` is congruent to `1`, or
+/// `None` if `a` and `modulus` are not coprime or `modulus` is not positive.
+///
+/// # Example:
+///
+/// ```
+/// use aoc::math::mod_inverse;
+///
+/// assert_eq!(mod_inverse(3, 11), Some(4));
+/// assert_eq!(mod_inverse(-3, 11), Some(7));
+/// assert_eq!(mod_inverse(4, 8), None);
+/// assert_eq!(mod_inverse(3, 0), None);
+/// assert_eq!(mod_inverse(3, -11), None);
+/// ```
+pub fn mod_inverse<T: Integer + Neg<Output = T>>(a: T, modulus: T) -> Option<T> {
+    if modulus <= T::ZERO {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus)?;
+    (g == T::ONE).then(|| x.rem_euclid(modulus))
+}
+
+/// Add two numbers already reduced modulo `modulus` without overflowing.
+fn add_mod<T: Integer>(a: T, b: T, modulus: T) -> T {
+    if a >= modulus - b {
+        a - (modulus - b)
+    } else {
+        a + b
+    }
+}
+
+/// Multiply two numbers modulo `modulus`, falling back to repeated doubling
+/// when the product would overflow.
+fn mul_mod<T: Integer>(a: T, b: T, modulus: T) -> T {
+    let (mut a, mut b) = (a.rem_euclid(modulus), b.rem_euclid(modulus));
+    if let Some(product) = a.checked_mul(b) {
+        return product % modulus;
+    }
+
+    let mut result = T::ZERO;
+    while b > T::ZERO {
+        if b % T::TWO == T::ONE {
+            result = add_mod(result, a, modulus);
+        }
+        a = add_mod(a, a, modulus);
+        b = b / T::TWO;
+    }
+    result
+}
+
+/// Modular Exponentiation
+///
+/// Calculate `base` to the power of `exponent` modulo `modulus` by repeated
+/// squaring.  Intermediate products never overflow, so any positive modulus
+/// that fits in `T` can be used.  Negative exponents are treated as zero.
+///
+/// # Example:
+///
+/// ```
+/// use aoc::math::mod_pow;
+///
+/// assert_eq!(mod_pow(4, 13, 497), 445);
+/// assert_eq!(mod_pow(2_u64, 64, u64::MAX), 1);
+/// assert_eq!(mod_pow(-2, 3, 5), 2);
+/// ```
+pub fn mod_pow<T: Integer>(base: T, exponent: T, modulus: T) -> T {
+    let mut result = T::ONE % modulus;
+    let mut base = base.rem_euclid(modulus);
+    let mut exponent = exponent;
+
+    while exponent > T::ZERO {
+        if exponent % T::TWO == T::ONE {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exponent = exponent / T::TWO;
+    }
+    result
+}
+
+/// Chinese Remainder Theorem
+///
+/// Combine congruences `x ≡ residue (mod modulus)` into a single
+/// `(residue, modulus)` satisfying all of them, where the modulus is the
+/// least common multiple of the inputs.  The moduli do not need to be
+/// coprime.  Returns `None` if a modulus is not positive, the congruences
+/// contradict each other or the combined modulus does not fit in `T`.
+///
+/// # Example:
+///
+/// ```
+/// use aoc::math::crt;
+///
+/// assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+/// assert_eq!(crt(&[(3, 4), (5, 6)]), Some((11, 12)));
+/// assert_eq!(crt(&[(0, 4), (1, 6)]), None);
+/// assert_eq!(crt::<i64>(&[]), Some((0, 1)));
+/// assert_eq!(crt(&[(2, 3), (3, 0)]), None);
+/// assert_eq!(crt(&[(2, 3), (3, -5)]), None);
+/// ```
+pub fn crt<T: Integer + Neg<Output = T>>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    congruences.iter().try_fold(
+        (T::ZERO, T::ONE),
+        |(residue, modulus), &(other_residue, other_modulus)| {
+            if other_modulus <= T::ZERO {
+                return None;
+            }
+            let other_residue = other_residue.rem_euclid(other_modulus);
+            let g = gcd(modulus, other_modulus);
+            let difference = other_residue - residue;
+            if difference % g != T::ZERO {
+                return None;
+            }
+
+            // Solve residue + modulus * k ≡ other_residue for k.
+            let step = other_modulus / g;
+            let inverse = mod_inverse(modulus / g, step)?;
+            let k = mul_mod(difference / g, inverse, step);
+            let combined = (modulus / g).checked_mul(other_modulus)?;
+            Some((residue + modulus * k, combined))
+        },
+    )
+}
+
+/// Find the earliest time at which several repeating events all happen
+/// together, given when each first happens and how often it repeats.
+///
+/// Unlike taking the [`lcm`] of the periods, this handles cycles with a
+/// lead-in, where the first hit is not a multiple of the period.  Returns
+/// `None` if a period is not positive, the events never line up or the time
+/// does not fit in `T`.
+///
+/// # Example:
+///
+/// ```
+/// use aoc::math::earliest_common_time;
+///
+/// // Events first seen at 2, 3 and 2, then every 3, 5 and 7 steps.
+/// assert_eq!(earliest_common_time(&[(2, 3), (3, 5), (2, 7)]), Some(23));
+///
+/// // The lead-in can be longer than the period.
+/// assert_eq!(earliest_common_time(&[(10, 2), (3, 3)]), Some(12));
+///
+/// // Without a lead-in this is the least common multiple.
+/// assert_eq!(earliest_common_time(&[(4_i64, 4), (6, 6)]), Some(12));
+///
+/// assert_eq!(earliest_common_time(&[(0, 2), (1, 2)]), None);
+///
+/// // Rounding up to the lead-in does not overflow on its own.
+/// assert_eq!(earliest_common_time(&[(i64::MAX, 2)]), Some(i64::MAX));
+/// assert_eq!(earliest_common_time(&[(i64::MAX - 1, 3), (1, 2)]), None);
+/// ```
+pub fn earliest_common_time<T: Integer + Neg<Output = T>>(cycles: &[(T, T)]) -> Option<T> {
+    let (residue, modulus) = crt(cycles)?;
+    let start = cycles
+        .iter()
+        .map(|(first_hit, _)| *first_hit)
+        .max()
+        .unwrap_or(T::ZERO);
+
+    if residue >= start {
+        return Some(residue);
+    }
+    // Round up to the first solution at or after every event has started.
+    // Both are non-negative here, so the difference cannot overflow.
+    let periods = (start - residue - T::ONE) / modulus + T::ONE;
+    periods.checked_mul(modulus)?.checked_add(residue)
+}
+
+/// Integer Square Root
+///
+/// Calculate the largest integer whose square is at most `n`, exactly and
+/// without going through floating point.
+///
+/// # Panics
+///
+/// Panics if `n` is negative.
+///
+/// # Example:
+///
 /// ```
-pub fn gcd(a: i64, b: i64) -> i64 {
-    if b == 0 {
-        return a;
+/// use aoc::math::isqrt;
+///
+/// assert_eq!(isqrt(24_u64), 4);
+/// assert_eq!(isqrt(25_u64), 5);
+/// assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+/// assert_eq!(isqrt(u128::MAX), u64::MAX as u128);
+/// ```
+pub fn isqrt<T: Integer>(n: T) -> T {
+    assert!(n >= T::ZERO, "square root of a negative number");
+    if n < T::TWO {
+        return n;
     }
-    gcd(b, a % b)
-}
\ No newline at end of file
+
+    // Newton's method, starting above the root so the estimate only falls.
+    let mut estimate = n / T::TWO + T::ONE;
+    let mut next = (estimate + n / estimate) / T::TWO;
+    while next < estimate {
+        estimate = next;
+        next = (estimate + n / estimate) / T::TWO;
+    }
+    estimate
+}
+
+/// Find the integers `x` where `a * x * x + b * x + c > 0`, for a quadratic
+/// that opens downward.
+///
+/// The solutions lie strictly between the two roots, and are found with
+/// exact integer arithmetic so roots that land on an integer are excluded.
+/// Any coefficients are supported without overflowing.  Returns `None` if
+/// there are no solutions, or if `a` is not negative, since the solutions
+/// are then unbounded or not an interval.
+///
+/// # Example:
+///
+/// ```
+/// use aoc::math::integer_solutions_between;
+///
+/// // Holding a button for x of 30 milliseconds beats a record of 200 when
+/// // x * (30 - x) > 200, or -x² + 30x - 200 > 0.  The roots are exactly 10
+/// // and 20, which only tie the record.
+/// assert_eq!(integer_solutions_between(-1, 30, -200), Some(11..=19));
+///
+/// assert_eq!(integer_solutions_between(-1, 7, -9), Some(2..=5));
+/// assert_eq!(integer_solutions_between(-1, 4, -4), None);
+/// assert_eq!(integer_solutions_between(i64::MIN, i64::MAX, i64::MAX), Some(0..=1));
+/// assert_eq!(integer_solutions_between(-1, i64::MAX, i64::MAX), Some(0..=i64::MAX));
+///
+/// // Quadratics that open upward, and lines, are not supported.
+/// assert_eq!(integer_solutions_between(1, 0, -4), None);
+/// assert_eq!(integer_solutions_between(0, 1, 0), None);
+/// ```
+pub fn integer_solutions_between(a: i64, b: i64, c: i64) -> Option<RangeInclusive<i64>> {
+    if a >= 0 {
+        return None;
+    }
+    let (a, b, c) = (-i128::from(a), i128::from(b), i128::from(c));
+
+    // With a now positive, the inequality is x * (b - a * x) > -c.  If the
+    // product overflows it is far larger than c, so only its sign matters.
+    let positive = |x: i128| {
+        let rest = b - a * x;
+        match x.checked_mul(rest) {
+            Some(product) => product > -c,
+            None => (x > 0) == (rest > 0),
+        }
+    };
+
+    // The largest value at an integer is next to the vertex at b / 2a.
+    let vertex = b.div_euclid(2 * a);
+    let peak = [vertex, vertex + 1].into_iter().find(|x| positive(*x))?;
+
+    // The discriminant b² + 4ac can need more than 128 bits, but a quarter
+    // of it always fits, and twice its square root is within two of the
+    // discriminant's square root.  The estimated roots are then corrected
+    // exactly.
+    let root = 2 * isqrt((b * b) / 4 + a * c);
+    let mut low = (b - root).div_euclid(2 * a).min(peak);
+    while !positive(low) {
+        low += 1;
+    }
+    while positive(low - 1) {
+        low -= 1;
+    }
+    let mut high = (-(-(b + root)).div_euclid(2 * a)).max(peak);
+    while !positive(high) {
+        high -= 1;
+    }
+    while positive(high + 1) {
+        high += 1;
+    }
+
+    let bound = |x: i128| {
+        i64::try_from(x).expect("the solutions of a quadratic with i64 coefficients fit in i64")
+    };
+    Some(bound(low)..=bound(high))
+}
+
+/// Count the integers `x` where `a * x * x + b * x + c > 0`, using
+/// [`integer_solutions_between`].  Returns `None` if `a` is not negative.
+///
+/// # Example:
+///
+/// ```
+/// use aoc::math::count_integer_solutions;
+///
+/// assert_eq!(count_integer_solutions(-1, 71_530, -940_200), Some(71_503));
+/// assert_eq!(count_integer_solutions(-1, 4, -4), Some(0));
+/// assert_eq!(count_integer_solutions(i64::MIN, i64::MAX, i64::MAX), Some(2));
+/// assert_eq!(count_integer_solutions(1, 0, -4), None);
+/// ```
+pub fn count_integer_solutions(a: i64, b: i64, c: i64) -> Option<u64> {
+    if a >= 0 {
+        return None;
+    }
+    Some(
+        integer_solutions_between(a, b, c)
+            .map_or(0, |range| range.end().abs_diff(*range.start()) + 1),
+    )
+}
@@ -0,0 +1,157 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use super::Rational;
+
+/// Extrapolate a sequence sampled at `0, 1, 2, ...` to position `n`, using
+/// the lowest degree polynomial through every value.
+///
+/// Positions before the start are negative, so `n = -1` gives the value
+/// before the first and `n = sequence.len()` the value after the last.  Works
+/// on integers, where the result is always exact, or on [`Rational`]s.
+/// Returns `None` if the sequence is empty.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::math::{extrapolate, Rational};
+///
+/// let sequence = [10_i128, 13, 16, 21, 30, 45];
+/// assert_eq!(extrapolate(&sequence, 6), Some(68));
+/// assert_eq!(extrapolate(&sequence, -1), Some(5));
+///
+/// let halves = [0, 1, 2].map(|n| Rational::new(n, 2));
+/// assert_eq!(extrapolate(&halves, 5), Some(Rational::new(5, 2)));
+/// ```
+pub fn extrapolate<T>(sequence: &[T], n: i64) -> Option<T>
+where
+    T: Copy
+        + PartialEq
+        + From<i64>
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>,
+{
+    let zero = T::from(0);
+    let mut differences = sequence.to_vec();
+    let mut result = *differences.first()?;
+    let mut binomial = T::from(1);
+
+    // Newton's forward difference formula, summing each leading difference
+    // times the binomial coefficient C(n, k).  The coefficient stays a whole
+    // number at every step, so integer division is exact.
+    for k in 0..sequence.len() as i64 - 1 {
+        differences = differences
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .collect();
+        if differences.iter().all(|difference| *difference == zero) {
+            break;
+        }
+        binomial = binomial * T::from(n - k) / T::from(k + 1);
+        result = result + differences[0] * binomial;
+    }
+
+    Some(result)
+}
+
+/// A polynomial with exact rational coefficients.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::math::{Polynomial, Rational};
+///
+/// // Triangular numbers, n * (n + 1) / 2.
+/// let triangular = Polynomial::fit(&[(1, 1), (2, 3), (3, 6), (4, 10)]).unwrap();
+/// assert_eq!(triangular.degree(), 2);
+/// assert_eq!(
+///     triangular.coefficients(),
+///     &[Rational::ZERO, Rational::new(1, 2), Rational::new(1, 2)]
+/// );
+/// assert_eq!(triangular.evaluate(100).to_integer(), Some(5050));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polynomial {
+    coefficients: Vec<Rational>,
+}
+
+impl Polynomial {
+    /// Fit the lowest degree polynomial through the points `(x, y)`, using
+    /// Newton's divided differences.
+    ///
+    /// The points can be spaced unevenly, as when sampling a quadratic every
+    /// few hundred steps.  Returns `None` if there are no points or two points
+    /// share an `x`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::math::Polynomial;
+    ///
+    /// let quadratic = Polynomial::fit(&[(65, 3_776), (196, 33_652), (327, 93_270)]).unwrap();
+    /// assert_eq!(quadratic.degree(), 2);
+    /// assert_eq!(quadratic.evaluate(458).to_integer(), Some(182_630));
+    ///
+    /// assert_eq!(Polynomial::fit(&[(1, 2), (1, 3)]), None);
+    /// ```
+    pub fn fit(points: &[(i128, i128)]) -> Option<Self> {
+        if points.is_empty() {
+            return None;
+        }
+        let xs = points.iter().map(|(x, _)| *x).collect::<Vec<_>>();
+        let mut newton = points
+            .iter()
+            .map(|(_, y)| Rational::from(*y))
+            .collect::<Vec<_>>();
+
+        for j in 1..points.len() {
+            for i in (j..points.len()).rev() {
+                let spacing = xs[i] - xs[i - j];
+                if spacing == 0 {
+                    return None;
+                }
+                newton[i] = (newton[i] - newton[i - 1]) / Rational::from(spacing);
+            }
+        }
+
+        // Expand the Newton form one factor of (x - xs[k]) at a time.
+        let mut coefficients = vec![newton[points.len() - 1]];
+        for k in (0..points.len() - 1).rev() {
+            let shift = Rational::from(xs[k]);
+            let mut expanded = vec![Rational::ZERO; coefficients.len() + 1];
+            for (power, coefficient) in coefficients.iter().enumerate() {
+                expanded[power + 1] = expanded[power + 1] + *coefficient;
+                expanded[power] = expanded[power] - *coefficient * shift;
+            }
+            expanded[0] = expanded[0] + newton[k];
+            coefficients = expanded;
+        }
+
+        while coefficients.len() > 1 && coefficients.last() == Some(&Rational::ZERO) {
+            coefficients.pop();
+        }
+        Some(Self { coefficients })
+    }
+
+    /// The coefficients from the constant term up to the highest power.
+    pub fn coefficients(&self) -> &[Rational] {
+        &self.coefficients
+    }
+
+    /// The highest power with a non-zero coefficient, or zero for a constant.
+    pub fn degree(&self) -> usize {
+        self.coefficients.len() - 1
+    }
+
+    /// The value of the polynomial at `x`.
+    pub fn evaluate(&self, x: impl Into<Rational>) -> Rational {
+        let x = x.into();
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(Rational::ZERO, |value, coefficient| {
+                value * x + *coefficient
+            })
+    }
+}
@@ -0,0 +1,107 @@
+use miette::{Diagnostic, SourceSpan};
+use nom::error::{VerboseError, VerboseErrorKind};
+use nom::{Offset, Parser};
+use thiserror::Error;
+
+/// A nom parse failure rendered as a miette diagnostic.
+///
+/// The full puzzle input is kept as the source code so the report can point
+/// at the exact offset where parsing went wrong.
+#[derive(Error, Diagnostic, Debug)]
+#[error("failed to parse input")]
+#[diagnostic(code(aoc::parse_error))]
+pub struct ParseError {
+    #[source_code]
+    input: String,
+
+    #[label("{reason}")]
+    span: SourceSpan,
+
+    reason: String,
+}
+
+impl ParseError {
+    /// Build a diagnostic from a nom error produced while parsing `input`.
+    ///
+    /// The label points at the innermost failure, which is where the parser
+    /// actually gave up.
+    pub fn new(input: &str, error: nom::Err<VerboseError<&str>>) -> Self {
+        match error {
+            nom::Err::Incomplete(_) => {
+                Self::at(input, input.len(), "unexpected end of input".into())
+            }
+            nom::Err::Error(error) | nom::Err::Failure(error) => {
+                let Some((remaining, kind)) = error.errors.first() else {
+                    return Self::at(input, 0, "unknown error".into());
+                };
+                let reason = match kind {
+                    VerboseErrorKind::Context(context) => format!("expected {context}"),
+                    VerboseErrorKind::Char(c) => format!("expected {c:?}"),
+                    VerboseErrorKind::Nom(kind) => format!("expected {}", kind.description()),
+                };
+                Self::at(input, input.offset(remaining), reason)
+            }
+        }
+    }
+
+    /// Build a diagnostic for input left over after a successful parse.
+    pub fn trailing(input: &str, remaining: &str) -> Self {
+        let remaining = remaining.trim_start();
+        Self::at(
+            input,
+            input.offset(remaining),
+            "unexpected trailing input".into(),
+        )
+    }
+
+    fn at(input: &str, offset: usize, reason: String) -> Self {
+        Self {
+            input: input.to_string(),
+            span: offset.into(),
+            reason,
+        }
+    }
+
+    /// The byte offset into the input where parsing failed.
+    pub fn offset(&self) -> usize {
+        self.span.offset()
+    }
+
+    /// A short description of what went wrong at `offset`.
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+/// Run `parser` over the whole of `input`.
+///
+/// Unlike calling the parser directly, any input left unconsumed is reported
+/// as an error instead of being silently dropped.  Trailing whitespace, such
+/// as the final newline of an input file, is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use nom::{character::complete::{newline, u32}, multi::separated_list1};
+///
+/// let numbers = aoc::parse::finish("1\n2\n3\n", separated_list1(newline, u32)).unwrap();
+/// assert_eq!(numbers, vec![1, 2, 3]);
+///
+/// let error = aoc::parse::finish("1\n2\nx\n", separated_list1(newline, u32)).unwrap_err();
+/// assert_eq!(error.offset(), 4);
+/// assert_eq!(error.reason(), "unexpected trailing input");
+///
+/// let error = aoc::parse::finish("x", separated_list1(newline, u32)).unwrap_err();
+/// assert_eq!(error.offset(), 0);
+/// assert_eq!(error.reason(), "expected Digit");
+/// ```
+pub fn finish<'a, O>(
+    input: &'a str,
+    mut parser: impl Parser<&'a str, O, VerboseError<&'a str>>,
+) -> Result<O, ParseError> {
+    match parser.parse(input) {
+        Ok((remaining, output)) if remaining.trim().is_empty() => Ok(output),
+        Ok((remaining, _)) => Err(ParseError::trailing(input, remaining)),
+        Err(error) => Err(ParseError::new(input, error)),
+    }
+}
@@ -0,0 +1,297 @@
+use glam::IVec2;
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use crate::grid::{adjacent, Connectivity, GridLike};
+
+/// Bookkeeping shared by the search algorithms.
+///
+/// Every state seen is stored once in `states` together with the index of the
+/// state it was reached from and the best known cost, and `index` maps a
+/// state back to its slot.  This lets the priority queue hold plain indices,
+/// so states only need to be `Eq + Hash` and never `Ord`.
+struct Visited<S, C> {
+    states: Vec<(S, Option<usize>, C)>,
+    index: HashMap<S, usize>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy + Ord> Visited<S, C> {
+    fn new(start: S, cost: C) -> Self {
+        Self {
+            states: vec![(start.clone(), None, cost)],
+            index: HashMap::from([(start, 0)]),
+        }
+    }
+
+    /// Record that `state` can be reached from `parent` at `cost`, returning
+    /// its index if this is an improvement over what was known before.
+    fn relax(&mut self, state: S, parent: usize, cost: C) -> Option<usize> {
+        match self.index.entry(state) {
+            Entry::Vacant(entry) => {
+                let index = self.states.len();
+                self.states.push((entry.key().clone(), Some(parent), cost));
+                entry.insert(index);
+                Some(index)
+            }
+            Entry::Occupied(entry) => {
+                let index = *entry.get();
+                let slot = &mut self.states[index];
+                if cost < slot.2 {
+                    slot.1 = Some(parent);
+                    slot.2 = cost;
+                    Some(index)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Walk the parent links back from `index` to the start.
+    fn path(&self, mut index: usize) -> Vec<S> {
+        let mut path = vec![];
+        loop {
+            let (state, parent, _) = &self.states[index];
+            path.push(state.clone());
+            match parent {
+                Some(parent) => index = *parent,
+                None => break,
+            }
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// Breadth-first search.
+///
+/// Explores states reachable from `start` in order of the number of steps
+/// taken, where every step has the same cost.  Returns the number of steps to
+/// the first state satisfying `goal` along with the path from `start` to that
+/// state (inclusive of both ends), or `None` if no goal is reachable.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::search::bfs;
+///
+/// // Reach 10 from 1 by either doubling or adding one.
+/// let (steps, path) = bfs(1, |&n| [n * 2, n + 1], |&n| n == 10).unwrap();
+/// assert_eq!(steps, 4);
+/// assert_eq!(path, vec![1, 2, 4, 5, 10]);
+///
+/// assert!(bfs(1, |&n: &u32| (n < 5).then_some(n + 1), |&n| n == 10).is_none());
+/// ```
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    goal: impl Fn(&S) -> bool,
+) -> Option<(usize, Vec<S>)>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = Visited::new(start, 0);
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(index) = queue.pop_front() {
+        let (state, _, steps) = &visited.states[index];
+        if goal(state) {
+            return Some((*steps, visited.path(index)));
+        }
+
+        let steps = steps + 1;
+        for next in successors(&state.clone()) {
+            if let Some(next) = visited.relax(next, index, steps) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Dijkstra's shortest path search.
+///
+/// The `successors` closure returns every state reachable from the given
+/// state along with the (non-negative) cost of moving there.  Returns the
+/// total cost of the cheapest path to a state satisfying `goal`, together
+/// with that path, or `None` if no goal is reachable.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::search::dijkstra;
+///
+/// // A small weighted graph where the direct edge is not the cheapest.
+/// let edges = |&node: &char| match node {
+///     'a' => vec![('b', 7), ('c', 2)],
+///     'c' => vec![('b', 3)],
+///     'b' => vec![('d', 1)],
+///     _ => vec![],
+/// };
+///
+/// let (cost, path) = dijkstra('a', edges, |&node| node == 'd').unwrap();
+/// assert_eq!(cost, 6);
+/// assert_eq!(path, vec!['a', 'c', 'b', 'd']);
+/// ```
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    goal: impl Fn(&S) -> bool,
+) -> Option<(C, Vec<S>)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), goal)
+}
+
+/// A* shortest path search.
+///
+/// Like [`dijkstra`], but the search is guided by `heuristic`, an estimate of
+/// the remaining cost from a state to the goal.  The heuristic must never
+/// overestimate the true cost for the result to be the cheapest path.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::search::astar;
+/// use glam::IVec2;
+///
+/// // Walk on an open plane, one unit per step.
+/// let goal = IVec2::new(3, -2);
+/// let successors = |p: &IVec2| {
+///     [IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y].map(|d| (*p + d, 1))
+/// };
+/// let heuristic = |p: &IVec2| (goal - *p).abs().element_sum();
+///
+/// let (cost, path) = astar(IVec2::ZERO, successors, heuristic, |p| *p == goal).unwrap();
+/// assert_eq!(cost, 5);
+/// assert_eq!(path.len(), 6);
+/// assert_eq!(path.last(), Some(&goal));
+/// ```
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    heuristic: impl Fn(&S) -> C,
+    goal: impl Fn(&S) -> bool,
+) -> Option<(C, Vec<S>)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut queue = BinaryHeap::from([(Reverse(heuristic(&start)), Reverse(C::default()), 0)]);
+    let mut visited = Visited::new(start, C::default());
+
+    while let Some((_, Reverse(cost), index)) = queue.pop() {
+        let (state, _, best) = &visited.states[index];
+
+        // A cheaper route to this state was found after this entry was queued.
+        if cost > *best {
+            continue;
+        }
+
+        if goal(state) {
+            return Some((cost, visited.path(index)));
+        }
+
+        for (next, step) in successors(&state.clone()) {
+            let next_cost = cost + step;
+            let estimate = next_cost + heuristic(&next);
+            if let Some(next) = visited.relax(next, index, next_cost) {
+                queue.push((Reverse(estimate), Reverse(next_cost), next));
+            }
+        }
+    }
+
+    None
+}
+
+/// Breadth-first search over the cardinal neighbors of a grid.
+///
+/// Only cells present in the grid for which `passable` returns true are
+/// entered.  Returns the number of steps and the path from `start` to
+/// `goal`.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::grid::DenseGrid;
+/// use glam::IVec2;
+///
+/// let grid = DenseGrid::from_rows(vec![
+///     "..#".chars().collect(),
+///     "#..".chars().collect(),
+///     "...".chars().collect(),
+/// ])
+/// .unwrap();
+///
+/// let (steps, path) =
+///     aoc::search::bfs_grid(&grid, IVec2::new(0, 0), IVec2::new(2, 1), |c| *c != '#').unwrap();
+/// assert_eq!(steps, 3);
+/// assert_eq!(path[2], IVec2::new(1, 1));
+/// ```
+pub fn bfs_grid<G: GridLike>(
+    grid: &G,
+    start: IVec2,
+    goal: IVec2,
+    passable: impl Fn(&G::Item) -> bool,
+) -> Option<(usize, Vec<IVec2>)> {
+    bfs(
+        start,
+        |point| grid_successors(grid, point, &passable).map(|(next, _)| next),
+        |point| *point == goal,
+    )
+}
+
+/// A* search over the cardinal neighbors of a grid, using the manhattan
+/// distance to `goal` as the heuristic and a cost of one per step.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::grid::Grid;
+/// use glam::IVec2;
+///
+/// let grid = aoc::grid::locate(vec![
+///     "...".chars().collect(),
+///     ".#.".chars().collect(),
+///     ".#.".chars().collect(),
+/// ]);
+///
+/// let (steps, _) =
+///     aoc::search::astar_grid(&grid, IVec2::new(0, 2), IVec2::new(2, 2), |c| *c != '#').unwrap();
+/// assert_eq!(steps, 6);
+///
+/// let blocked = |c: &char| *c == '.';
+/// assert!(aoc::search::astar_grid(&grid, IVec2::new(0, 0), IVec2::new(1, 1), blocked).is_none());
+/// ```
+pub fn astar_grid<G: GridLike>(
+    grid: &G,
+    start: IVec2,
+    goal: IVec2,
+    passable: impl Fn(&G::Item) -> bool,
+) -> Option<(usize, Vec<IVec2>)> {
+    astar(
+        start,
+        |point| grid_successors(grid, point, &passable),
+        |point| (goal - *point).abs().element_sum() as usize,
+        |point| *point == goal,
+    )
+}
+
+fn grid_successors<'a, G: GridLike>(
+    grid: &'a G,
+    point: &IVec2,
+    passable: &'a impl Fn(&G::Item) -> bool,
+) -> impl Iterator<Item = (IVec2, usize)> + 'a {
+    adjacent(grid, point, Connectivity::Four)
+        .filter(|(_, _, value)| passable(value))
+        .map(|(_, next, _)| (next, 1))
+}
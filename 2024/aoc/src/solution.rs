@@ -0,0 +1,107 @@
+use std::fmt;
+
+/// The answer to one part of a puzzle.
+///
+/// Puzzles ask for unsigned counts, signed totals or strings, so each
+/// solution returns whichever fits and the runner only needs to print it.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::Answer;
+///
+/// assert_eq!(Answer::from(42usize), Answer::Integer(42));
+/// assert_eq!(Answer::from(-7i32), Answer::Signed(-7));
+/// assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(u64),
+    Signed(i64),
+    String(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(value) => write!(f, "{value}"),
+            Self::Signed(value) => write!(f, "{value}"),
+            Self::String(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($variant:ident, $target:ty, $($source:ty),+) => {
+        $(
+            impl From<$source> for Answer {
+                fn from(value: $source) -> Self {
+                    Self::$variant(value as $target)
+                }
+            }
+        )+
+    };
+}
+
+impl_from!(Integer, u64, u8, u16, u32, u64, usize);
+impl_from!(Signed, i64, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+/// A complete solution to one day's puzzle.
+///
+/// The raw input is parsed once into `Input`, which is then shared by both
+/// parts.  This lets parsing be timed and benchmarked separately from
+/// solving, and lets the runner treat every day the same way.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::{Answer, Solution};
+///
+/// struct Puzzle;
+///
+/// impl Solution for Puzzle {
+///     type Input = Vec<i64>;
+///     type Error = std::num::ParseIntError;
+///
+///     fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+///         input.lines().map(str::parse).collect()
+///     }
+///
+///     fn part1(input: &Self::Input) -> Result<Answer, Self::Error> {
+///         Ok(input.iter().sum::<i64>().into())
+///     }
+///
+///     fn part2(input: &Self::Input) -> Result<Answer, Self::Error> {
+///         Ok(input.iter().max().copied().unwrap_or_default().into())
+///     }
+/// }
+///
+/// let input = Puzzle::parse("3\n-5\n1").unwrap();
+/// assert_eq!(Puzzle::part1(&input).unwrap(), Answer::Signed(-1));
+/// assert_eq!(Puzzle::part2(&input).unwrap(), Answer::Signed(3));
+/// ```
+pub trait Solution {
+    /// The parsed puzzle input shared by both parts.
+    type Input;
+
+    /// The error returned when parsing or solving fails.
+    type Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error>;
+
+    fn part1(input: &Self::Input) -> Result<Answer, Self::Error>;
+
+    fn part2(input: &Self::Input) -> Result<Answer, Self::Error>;
+}
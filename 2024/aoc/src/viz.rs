@@ -0,0 +1,372 @@
+use glam::IVec2;
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use crate::grid::GridLike;
+
+mod image;
+
+pub use image::{write_gif, write_image, Image, ImageError};
+
+/// Clears the terminal and moves the cursor to the top-left corner.
+const CLEAR: &str = "\x1b[2J\x1b[H";
+
+/// A 24-bit color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GRAY: Rgb = Rgb(128, 128, 128);
+    pub const RED: Rgb = Rgb(220, 50, 47);
+    pub const GREEN: Rgb = Rgb(133, 153, 0);
+    pub const YELLOW: Rgb = Rgb(181, 137, 0);
+    pub const BLUE: Rgb = Rgb(38, 139, 210);
+    pub const MAGENTA: Rgb = Rgb(211, 54, 130);
+    pub const CYAN: Rgb = Rgb(42, 161, 152);
+}
+
+/// How a single cell is drawn in the terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Glyph {
+    pub character: char,
+    pub foreground: Option<Rgb>,
+    pub background: Option<Rgb>,
+}
+
+impl Glyph {
+    /// A glyph drawn in the terminal's default colors.
+    pub fn new(character: char) -> Self {
+        Self {
+            character,
+            foreground: None,
+            background: None,
+        }
+    }
+
+    pub fn foreground(self, color: Rgb) -> Self {
+        Self {
+            foreground: Some(color),
+            ..self
+        }
+    }
+
+    pub fn background(self, color: Rgb) -> Self {
+        Self {
+            background: Some(color),
+            ..self
+        }
+    }
+}
+
+/// A set of points drawn on top of the grid, such as visited cells, a path
+/// or the heads of beams.
+///
+/// Each of the character and colors is only replaced if the overlay sets
+/// it, so an overlay can for example highlight cells without hiding them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overlay {
+    points: HashSet<IVec2>,
+    character: Option<char>,
+    foreground: Option<Rgb>,
+    background: Option<Rgb>,
+}
+
+impl Overlay {
+    pub fn new(points: impl IntoIterator<Item = IVec2>) -> Self {
+        Self {
+            points: points.into_iter().collect(),
+            character: None,
+            foreground: None,
+            background: None,
+        }
+    }
+
+    pub fn character(self, character: char) -> Self {
+        Self {
+            character: Some(character),
+            ..self
+        }
+    }
+
+    pub fn foreground(self, color: Rgb) -> Self {
+        Self {
+            foreground: Some(color),
+            ..self
+        }
+    }
+
+    pub fn background(self, color: Rgb) -> Self {
+        Self {
+            background: Some(color),
+            ..self
+        }
+    }
+
+    fn apply(&self, point: &IVec2, glyph: Glyph) -> Glyph {
+        if !self.points.contains(point) {
+            return glyph;
+        }
+        Glyph {
+            character: self.character.unwrap_or(glyph.character),
+            foreground: self.foreground.or(glyph.foreground),
+            background: self.background.or(glyph.background),
+        }
+    }
+}
+
+/// The escape sequence selecting the given colors, resetting any that are
+/// not set.
+fn sgr(foreground: Option<Rgb>, background: Option<Rgb>) -> String {
+    let mut sequence = String::from("\x1b[0");
+    if let Some(Rgb(r, g, b)) = foreground {
+        write!(sequence, ";38;2;{r};{g};{b}").expect("writing to a string cannot fail");
+    }
+    if let Some(Rgb(r, g, b)) = background {
+        write!(sequence, ";48;2;{r};{g};{b}").expect("writing to a string cannot fail");
+    }
+    sequence.push('m');
+    sequence
+}
+
+/// Render the grid as a single frame of text, styling each cell with `style`
+/// and then drawing the overlays on top in order.
+///
+/// Points inside the grid's boundaries with no value are drawn as spaces.
+/// Colors are written as ANSI escape sequences, and only when they change,
+/// so a frame without any colors is plain text.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::viz::{Glyph, Overlay, Rgb};
+/// use glam::IVec2;
+///
+/// let grid = aoc::grid::locate(vec![vec!['.', '#'], vec!['.', '.']]);
+///
+/// let path = Overlay::new([IVec2::new(0, 0), IVec2::new(0, 1)]).character('o');
+/// let frame = aoc::viz::render(&grid, |c| Glyph::new(*c), &[path]);
+/// assert_eq!(frame, "o#\no.\n");
+///
+/// let walls = aoc::viz::render(
+///     &grid,
+///     |c| match c {
+///         '#' => Glyph::new('#').foreground(Rgb::RED),
+///         _ => Glyph::new(*c),
+///     },
+///     &[],
+/// );
+/// assert_eq!(walls, ".\x1b[0;38;2;220;50;47m#\x1b[0m\n..\n");
+/// ```
+pub fn render<G: GridLike>(
+    grid: &G,
+    style: impl Fn(&G::Item) -> Glyph,
+    overlays: &[Overlay],
+) -> String {
+    let (top_left, bottom_right) = grid.boundaries();
+    let mut frame = String::new();
+
+    for y in top_left.y..=bottom_right.y {
+        let mut colors = (None, None);
+        for x in top_left.x..=bottom_right.x {
+            let point = IVec2::new(x, y);
+            let glyph = grid.get(&point).map_or(Glyph::new(' '), &style);
+            let glyph = overlays
+                .iter()
+                .fold(glyph, |glyph, overlay| overlay.apply(&point, glyph));
+
+            if (glyph.foreground, glyph.background) != colors {
+                colors = (glyph.foreground, glyph.background);
+                frame.push_str(&sgr(glyph.foreground, glyph.background));
+            }
+            frame.push(glyph.character);
+        }
+        if colors != (None, None) {
+            frame.push_str("\x1b[0m");
+        }
+        frame.push('\n');
+    }
+
+    frame
+}
+
+/// The number of visible characters in a line, ignoring escape sequences.
+fn visible_width(line: &str) -> usize {
+    let mut width = 0;
+    let mut escaped = false;
+    for character in line.chars() {
+        match (escaped, character) {
+            (false, '\x1b') => escaped = true,
+            (false, _) => width += 1,
+            (true, 'm') => escaped = false,
+            (true, _) => {}
+        }
+    }
+    width
+}
+
+/// Escape a string for use inside a JSON string literal.
+fn json_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            c if c.is_control() => {
+                write!(escaped, "\\u{:04x}", c as u32).expect("writing to a string cannot fail")
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Play a sequence of frames in the terminal, clearing it before each one.
+///
+/// Returns an [`io::ErrorKind::InvalidInput`] error if `fps` is not a
+/// positive, finite number.
+///
+/// # Examples
+///
+/// ```
+/// let frames = vec!["#.\n".to_string(), ".#\n".to_string()];
+///
+/// let mut out = vec![];
+/// aoc::viz::replay(&frames, 1000.0, &mut out).unwrap();
+/// assert_eq!(String::from_utf8(out).unwrap(), "\x1b[2J\x1b[H#.\n\x1b[2J\x1b[H.#\n");
+///
+/// let error = aoc::viz::replay(&frames, 0.0, &mut vec![]).unwrap_err();
+/// assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+/// ```
+pub fn replay(frames: &[String], fps: f64, out: &mut impl Write) -> io::Result<()> {
+    if !(fps.is_finite() && fps > 0.0) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("frame rate must be positive and finite, got {fps}"),
+        ));
+    }
+
+    let interval = Duration::from_secs_f64(1.0 / fps);
+    for (index, frame) in frames.iter().enumerate() {
+        if index > 0 {
+            std::thread::sleep(interval);
+        }
+        write!(out, "{CLEAR}{frame}")?;
+        out.flush()?;
+    }
+    Ok(())
+}
+
+/// A sequence of frames captured from a simulation, which can be replayed in
+/// the terminal or saved as an [asciicast] recording.
+///
+/// [asciicast]: https://docs.asciinema.org/manual/asciicast/v2/
+///
+/// # Examples
+///
+/// ```
+/// use aoc::viz::Recording;
+///
+/// let mut recording = Recording::new(2.0);
+/// recording.push("#..\n".to_string());
+/// recording.push(".#.\n".to_string());
+///
+/// let mut cast = vec![];
+/// recording.write_cast(&mut cast).unwrap();
+/// let cast = String::from_utf8(cast).unwrap();
+/// let lines = cast.lines().collect::<Vec<_>>();
+/// assert_eq!(lines[0], r#"{"version": 2, "width": 3, "height": 1}"#);
+/// assert_eq!(lines[2], r#"[0.500000, "o", "\u001b[2J\u001b[H.#.\r\n"]"#);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recording {
+    fps: f64,
+    frames: Vec<String>,
+}
+
+impl Recording {
+    /// Create an empty recording played back at `fps` frames per second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fps` is not a positive, finite number.
+    pub fn new(fps: f64) -> Self {
+        assert!(
+            fps.is_finite() && fps > 0.0,
+            "frame rate must be positive and finite, got {fps}"
+        );
+        Self {
+            fps,
+            frames: vec![],
+        }
+    }
+
+    /// Add a frame to the end of the recording.
+    pub fn push(&mut self, frame: String) {
+        self.frames.push(frame);
+    }
+
+    pub fn frames(&self) -> &[String] {
+        &self.frames
+    }
+
+    /// The size of the terminal needed to show every frame.
+    pub fn size(&self) -> (usize, usize) {
+        let width = self
+            .frames
+            .iter()
+            .flat_map(|frame| frame.lines())
+            .map(visible_width)
+            .max()
+            .unwrap_or(0);
+        let height = self
+            .frames
+            .iter()
+            .map(|frame| frame.lines().count())
+            .max()
+            .unwrap_or(0);
+        (width, height)
+    }
+
+    /// Play the recording in the terminal.
+    pub fn replay(&self, out: &mut impl Write) -> io::Result<()> {
+        replay(&self.frames, self.fps, out)
+    }
+
+    /// Write the recording in the asciicast v2 format.
+    pub fn write_cast(&self, out: &mut impl Write) -> io::Result<()> {
+        let (width, height) = self.size();
+        writeln!(
+            out,
+            r#"{{"version": 2, "width": {width}, "height": {height}}}"#
+        )?;
+
+        let interval = 1.0 / self.fps;
+        for (index, frame) in self.frames.iter().enumerate() {
+            // The terminal is in raw mode while recording, so lines need an
+            // explicit carriage return.
+            let data = format!("{CLEAR}{frame}").replace('\n', "\r\n");
+            writeln!(
+                out,
+                r#"[{:.6}, "o", "{}"]"#,
+                index as f64 * interval,
+                json_escape(&data)
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Save the recording to a `.cast` file.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut file = BufWriter::new(File::create(path)?);
+        self.write_cast(&mut file)?;
+        file.flush()
+    }
+}
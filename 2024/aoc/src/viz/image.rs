@@ -0,0 +1,283 @@
+use glam::IVec2;
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use thiserror::Error;
+
+use super::Rgb;
+use crate::grid::GridLike;
+
+#[derive(Error, Debug)]
+pub enum ImageError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+
+    #[error(transparent)]
+    Png(#[from] png::EncodingError),
+
+    #[error(transparent)]
+    Gif(#[from] gif::EncodingError),
+
+    #[error("unsupported image format for {}, expected .ppm, .png or .gif", .0.display())]
+    UnsupportedFormat(PathBuf),
+
+    #[error("{width}x{height} is too large for a GIF, which is limited to 65535x65535")]
+    TooLarge { width: usize, height: usize },
+
+    #[error("the frames use {0} colors, but a GIF can only hold 256")]
+    TooManyColors(usize),
+
+    #[error("every frame of an animation must be the same size")]
+    MismatchedFrames,
+
+    #[error("an animation needs at least one frame")]
+    NoFrames,
+}
+
+/// An image rendered from a grid, with a square block of pixels per cell.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::viz::{Image, Rgb};
+/// use glam::IVec2;
+///
+/// let grid = aoc::grid::locate(vec![vec!['.', '#'], vec!['.', '.']]);
+/// let palette = |c: &char| if *c == '#' { Rgb::WHITE } else { Rgb::BLACK };
+///
+/// let mut image = Image::from_grid(&grid, 2, palette);
+/// assert_eq!((image.width(), image.height()), (4, 4));
+/// assert_eq!(image.pixel(2, 1), Some(Rgb::WHITE));
+///
+/// image.overlay([IVec2::new(0, 1)], Rgb::RED);
+/// assert_eq!(image.pixel(1, 3), Some(Rgb::RED));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    origin: IVec2,
+    scale: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// Render every cell within the grid's boundaries as a `scale` by
+    /// `scale` block colored by `palette`.  Points with no value are black.
+    pub fn from_grid<G: GridLike>(
+        grid: &G,
+        scale: usize,
+        palette: impl Fn(&G::Item) -> Rgb,
+    ) -> Self {
+        let scale = scale.max(1);
+        let (top_left, bottom_right) = grid.boundaries();
+        let cells = bottom_right - top_left + IVec2::ONE;
+
+        let mut image = Self {
+            width: cells.x as usize * scale,
+            height: cells.y as usize * scale,
+            origin: top_left,
+            scale,
+            pixels: vec![Rgb::BLACK; cells.x as usize * cells.y as usize * scale * scale],
+        };
+        for (point, value) in grid.cells() {
+            image.fill(point, palette(value));
+        }
+        image
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The color of a single pixel, or `None` outside the image.
+    pub fn pixel(&self, x: usize, y: usize) -> Option<Rgb> {
+        (x < self.width && y < self.height).then(|| self.pixels[y * self.width + x])
+    }
+
+    /// Color the cells at the given grid points, such as a path returned from
+    /// a search.  Points outside the image are ignored.
+    pub fn overlay(&mut self, points: impl IntoIterator<Item = IVec2>, color: Rgb) {
+        for point in points {
+            self.fill(point, color);
+        }
+    }
+
+    /// Color the block of pixels of a single cell.
+    fn fill(&mut self, point: IVec2, color: Rgb) {
+        let cell = point - self.origin;
+        let (Ok(x), Ok(y)) = (usize::try_from(cell.x), usize::try_from(cell.y)) else {
+            return;
+        };
+        let (x, y) = (x * self.scale, y * self.scale);
+        if x >= self.width || y >= self.height {
+            return;
+        }
+
+        for row in y..y + self.scale {
+            let start = row * self.width + x;
+            self.pixels[start..start + self.scale].fill(color);
+        }
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|Rgb(r, g, b)| [*r, *g, *b])
+            .collect()
+    }
+
+    /// Write the image as a binary PPM.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::viz::{Image, Rgb};
+    ///
+    /// let grid = aoc::grid::locate(vec![vec![1]]);
+    /// let image = Image::from_grid(&grid, 1, |_| Rgb(1, 2, 3));
+    ///
+    /// let mut ppm = vec![];
+    /// image.write_ppm(&mut ppm).unwrap();
+    /// assert_eq!(ppm, b"P6\n1 1\n255\n\x01\x02\x03");
+    /// ```
+    pub fn write_ppm(&self, out: &mut impl Write) -> Result<(), ImageError> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.bytes())?;
+        Ok(())
+    }
+
+    /// Write the image as a PNG.
+    pub fn write_png(&self, out: &mut impl Write) -> Result<(), ImageError> {
+        let mut encoder = png::Encoder::new(out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.write_header()?.write_image_data(&self.bytes())?;
+        Ok(())
+    }
+
+    /// Save the image, choosing the format from the file extension.
+    ///
+    /// The image is encoded in memory first, so the file is only written once
+    /// encoding has succeeded.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ImageError> {
+        let path = path.as_ref();
+        let extension = path.extension().and_then(|extension| extension.to_str());
+
+        let mut encoded = vec![];
+        match extension {
+            Some("ppm") => self.write_ppm(&mut encoded)?,
+            Some("png") => self.write_png(&mut encoded)?,
+            Some("gif") => write_gif(std::slice::from_ref(self), Duration::ZERO, &mut encoded)?,
+            _ => return Err(ImageError::UnsupportedFormat(path.to_path_buf())),
+        }
+        fs::write(path, encoded)?;
+        Ok(())
+    }
+}
+
+/// Write a grid to an image file with one pixel per cell, colored by
+/// `palette`.
+///
+/// The format is chosen from the extension of `path`, which may be `.ppm`,
+/// `.png` or `.gif`.  Use [`Image`] directly to scale cells up or to draw a
+/// path on top.
+///
+/// # Examples
+///
+/// ```no_run
+/// use aoc::viz::Rgb;
+///
+/// let grid = aoc::grid::locate(vec![vec!['.', '#'], vec!['#', '.']]);
+/// aoc::viz::write_image(&grid, "grid.png", |c| {
+///     if *c == '#' { Rgb::WHITE } else { Rgb::BLACK }
+/// })
+/// .unwrap();
+/// ```
+pub fn write_image<G: GridLike>(
+    grid: &G,
+    path: impl AsRef<Path>,
+    palette: impl Fn(&G::Item) -> Rgb,
+) -> Result<(), ImageError> {
+    Image::from_grid(grid, 1, palette).save(path)
+}
+
+/// Assemble frames into an animated GIF that loops forever, showing each
+/// frame for `delay`.
+///
+/// There must be at least one frame, every frame must be the same size, and
+/// the frames together may use at most 256 distinct colors.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::viz::{Image, Rgb};
+/// use std::time::Duration;
+///
+/// let grid = aoc::grid::locate(vec![vec![0, 1], vec![1, 0]]);
+/// let frames = [Rgb::RED, Rgb::BLUE]
+///     .map(|color| Image::from_grid(&grid, 4, |v| if *v == 1 { color } else { Rgb::BLACK }));
+///
+/// let mut gif = vec![];
+/// aoc::viz::write_gif(&frames, Duration::from_millis(100), &mut gif).unwrap();
+/// assert!(gif.starts_with(b"GIF89a"));
+///
+/// let error = aoc::viz::write_gif(&[], Duration::ZERO, &mut vec![]).unwrap_err();
+/// assert!(matches!(error, aoc::viz::ImageError::NoFrames));
+/// ```
+pub fn write_gif(
+    frames: &[Image],
+    delay: Duration,
+    out: &mut impl Write,
+) -> Result<(), ImageError> {
+    let Some(first) = frames.first() else {
+        return Err(ImageError::NoFrames);
+    };
+    if frames
+        .iter()
+        .any(|frame| (frame.width, frame.height) != (first.width, first.height))
+    {
+        return Err(ImageError::MismatchedFrames);
+    }
+    let (Ok(width), Ok(height)) = (u16::try_from(first.width), u16::try_from(first.height)) else {
+        return Err(ImageError::TooLarge {
+            width: first.width,
+            height: first.height,
+        });
+    };
+
+    let mut colors: HashMap<Rgb, u8> = HashMap::new();
+    let mut palette = vec![];
+    for color in frames.iter().flat_map(|frame| &frame.pixels) {
+        if colors.contains_key(color) {
+            continue;
+        }
+        let index =
+            u8::try_from(colors.len()).map_err(|_| ImageError::TooManyColors(colors.len() + 1))?;
+        colors.insert(*color, index);
+        palette.extend([color.0, color.1, color.2]);
+    }
+
+    let mut encoder = gif::Encoder::new(out, width, height, &palette)?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+
+    let delay = u16::try_from(delay.as_millis() / 10).unwrap_or(u16::MAX);
+    for frame in frames {
+        let indices = frame
+            .pixels
+            .iter()
+            .map(|color| colors[color])
+            .collect::<Vec<_>>();
+        let mut frame = gif::Frame::from_indexed_pixels(width, height, indices, None);
+        frame.delay = delay;
+        encoder.write_frame(&frame)?;
+    }
+
+    Ok(())
+}
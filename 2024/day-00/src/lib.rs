@@ -2,3 +2,25 @@ pub mod error;
 
 pub mod part1;
 pub mod part2;
+
+use aoc::{Answer, Solution};
+use error::AocError;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = String;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Self::Error> {
+        part1::solve(input).map(Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Self::Error> {
+        part2::solve(input).map(Answer::from)
+    }
+}
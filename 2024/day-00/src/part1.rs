@@ -1,7 +1,13 @@
 use crate::error::AocError;
+use crate::Puzzle;
+use aoc::Solution;
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<u64, AocError> {
+    solve(&Puzzle::parse(input)?)
+}
+
+pub fn solve(_input: &str) -> miette::Result<u64, AocError> {
     Ok(0)
 }
 
@@ -2,3 +2,41 @@ pub mod error;
 
 pub mod part1;
 pub mod part2;
+
+use aoc::{Answer, Solution};
+use error::AocError;
+use nom::{character, combinator, error::VerboseError, multi, sequence, IResult};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<(u64, u64)>;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(aoc::parse::finish(input, parse)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Self::Error> {
+        part1::solve(input).map(Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Self::Error> {
+        part2::solve(input).map(Answer::from)
+    }
+}
+
+fn parse_line(input: &str) -> IResult<&str, (u64, u64), VerboseError<&str>> {
+    combinator::map(
+        sequence::tuple((
+            character::complete::u64,
+            character::complete::space1,
+            character::complete::u64,
+        )),
+        |(num1, _, num2)| (num1, num2),
+    )(input)
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<(u64, u64)>, VerboseError<&str>> {
+    multi::separated_list1(character::complete::newline, parse_line)(input)
+}
@@ -1,12 +1,13 @@
 use crate::error::AocError;
-use nom::IResult;
-use nom::{character, combinator, multi, sequence};
+use crate::Puzzle;
+use aoc::Solution;
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<u64, AocError> {
-    let (input, pairs) = parse(input).unwrap();
-    debug_assert!(input.is_empty());
+    solve(&Puzzle::parse(input)?)
+}
 
+pub fn solve(pairs: &[(u64, u64)]) -> miette::Result<u64, AocError> {
     let (mut lhs, mut rhs): (Vec<u64>, Vec<u64>) = pairs.iter().cloned().unzip();
 
     lhs.sort();
@@ -20,20 +21,6 @@ pub fn process(input: &str) -> miette::Result<u64, AocError> {
     Ok(result)
 }
 
-fn parse_line(input: &str) -> IResult<&str, (u64, u64)> {
-    combinator::map(
-        sequence::tuple((
-            character::complete::u64,
-            character::complete::space1,
-            character::complete::u64,
-        )),
-        |(num1, _, num2)| (num1, num2),
-    )(input)
-}
-fn parse(input: &str) -> IResult<&str, Vec<(u64, u64)>> {
-    multi::separated_list1(character::complete::newline, parse_line)(input)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2,3 +2,34 @@ pub mod error;
 
 pub mod part1;
 pub mod part2;
+
+use aoc::{Answer, Solution};
+use error::AocError;
+use nom::{character, error::VerboseError, multi, IResult};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Vec<u32>>;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(aoc::parse::finish(input, parse)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Self::Error> {
+        part1::solve(input).map(Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Self::Error> {
+        part2::solve(input).map(Answer::from)
+    }
+}
+
+pub(crate) fn parse_line(input: &str) -> IResult<&str, Vec<u32>, VerboseError<&str>> {
+    multi::separated_list1(character::complete::space1, character::complete::u32)(input)
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<Vec<u32>>, VerboseError<&str>> {
+    multi::separated_list1(character::complete::newline, parse_line)(input)
+}
@@ -1,5 +1,6 @@
 use crate::error::AocError;
-use nom::{character, multi, IResult};
+use crate::Puzzle;
+use aoc::Solution;
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum SafetyResult {
@@ -7,14 +8,6 @@ pub enum SafetyResult {
     Unsafe,
 }
 
-fn parse_line(input: &str) -> IResult<&str, Vec<u32>> {
-    multi::separated_list1(character::complete::space1, character::complete::u32)(input)
-}
-
-fn parse(input: &str) -> IResult<&str, Vec<Vec<u32>>> {
-    multi::separated_list1(character::complete::newline, parse_line)(input)
-}
-
 fn check_decreasing(report: &[u32]) -> SafetyResult {
     let mut iter = report.iter().peekable();
 
@@ -102,9 +95,10 @@ fn perform_check(report: &[u32]) -> SafetyResult {
 }
 
 pub fn process(input: &str) -> miette::Result<u64, AocError> {
-    let (input, report) = parse(input).unwrap();
-    debug_assert!(input.is_empty());
+    solve(&Puzzle::parse(input)?)
+}
 
+pub fn solve(report: &[Vec<u32>]) -> miette::Result<u64, AocError> {
     let result = report
         .iter()
         .map(|line| perform_check(line))
@@ -116,6 +110,7 @@ pub fn process(input: &str) -> miette::Result<u64, AocError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parse_line;
     use rstest::rstest;
 
     #[test_log::test(rstest)]
@@ -1,8 +1,14 @@
 use crate::error::AocError;
+use crate::Puzzle;
+use aoc::Solution;
 use regex::Regex;
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<u64, AocError> {
+    solve(&Puzzle::parse(input)?)
+}
+
+pub fn solve(input: &str) -> miette::Result<u64, AocError> {
     let pairs = parse(input);
 
     let result = pairs.iter().map(|(x, y)| *x * *y).sum::<u64>();
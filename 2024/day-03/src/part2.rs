@@ -1,8 +1,14 @@
 use crate::error::AocError;
+use crate::Puzzle;
+use aoc::Solution;
 use regex::Regex;
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<u64, AocError> {
+    solve(&Puzzle::parse(input)?)
+}
+
+pub fn solve(input: &str) -> miette::Result<u64, AocError> {
     let pairs = parse(input);
 
     let result = pairs.iter().map(|(x, y)| *x * *y).sum::<u64>();
@@ -15,7 +21,7 @@ fn parse(input: &str) -> Vec<(u64, u64)> {
 
     let re = Regex::new(r"(^|do\(\))(.*?)(don\'t\(\)|$)").unwrap();
     re.captures_iter(&input)
-        .map(|group| {
+        .flat_map(|group| {
             let re = Regex::new(r"(mul\(\d+,\d+\))").unwrap();
             re.captures_iter(group.get(2).unwrap().as_str())
                 .map(|cap| {
@@ -31,7 +37,6 @@ fn parse(input: &str) -> Vec<(u64, u64)> {
                 })
                 .collect::<Vec<(u64, u64)>>()
         })
-        .flatten()
         .collect()
 }
 
@@ -1,9 +1,15 @@
+use aoc::Solution;
 use day_04::*;
 
 fn main() {
     divan::main();
 }
 
+#[divan::bench]
+fn parse() {
+    Puzzle::parse(divan::black_box(include_str!("../input.txt"))).unwrap();
+}
+
 #[divan::bench]
 fn part1() {
     part1::process(divan::black_box(include_str!("../input.txt"))).unwrap();
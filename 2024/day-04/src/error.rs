@@ -6,4 +6,12 @@ pub enum AocError {
     #[error(transparent)]
     #[diagnostic(code(aoc::io_error))]
     IoError(#[from] std::io::Error),
+
+    #[error(transparent)]
+    #[diagnostic(code(aoc::grid_parse_error))]
+    GridParseError(#[from] aoc::grid::GridParseError),
+
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Parse(#[from] aoc::parse::ParseError),
 }
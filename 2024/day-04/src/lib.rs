@@ -2,3 +2,61 @@ pub mod error;
 
 pub mod part1;
 pub mod part2;
+
+use aoc::grid::DenseGrid;
+use aoc::{Answer, Solution};
+use error::AocError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Tile {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'X' => Ok(Self::X),
+            'M' => Ok(Self::M),
+            'A' => Ok(Self::A),
+            'S' => Ok(Self::S),
+            _ => Err(c),
+        }
+    }
+}
+
+impl std::fmt::Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Self::X => 'X',
+            Self::M => 'M',
+            Self::A => 'A',
+            Self::S => 'S',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = DenseGrid<Tile>;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let (grid, _) = aoc::grid::parse_dense(input, &[])?;
+        Ok(grid)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Self::Error> {
+        part1::solve(input).map(Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Self::Error> {
+        part2::solve(input).map(Answer::from)
+    }
+}
@@ -0,0 +1,325 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+use serde::Deserialize;
+
+use crate::error::RunnerError;
+use crate::year_dir;
+
+/// Frames belonging to the allocator or the standard collections, which are
+/// skipped when attributing an allocation to a site in the solution.
+const INTERNAL_FRAMES: &[&str] = &[
+    "[root]",
+    "alloc::",
+    "<alloc::",
+    "core::",
+    "<core::",
+    "std::",
+    "<std::",
+    "hashbrown::",
+    "<hashbrown::",
+    "dhat::",
+    "<dhat::",
+];
+
+/// The parts of a `dhat-heap.json` file the report needs.
+#[derive(Debug, Deserialize)]
+struct DhatFile {
+    pps: Vec<ProgramPoint>,
+    ftbl: Vec<String>,
+}
+
+/// The allocations made from a single call stack.
+#[derive(Debug, Deserialize)]
+struct ProgramPoint {
+    /// Total bytes allocated.
+    tb: u64,
+    /// Total blocks allocated.
+    tbk: u64,
+    /// Bytes live when the heap was at its largest.
+    gb: u64,
+    /// Blocks live when the heap was at its largest.
+    gbk: u64,
+    /// Indices into the frame table, innermost first.
+    fs: Vec<usize>,
+}
+
+/// A call site along with everything it allocated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Site {
+    pub frame: String,
+    pub bytes: u64,
+    pub blocks: u64,
+}
+
+/// The heap profile of a single day and part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Profile {
+    pub day: u8,
+    pub part: u8,
+    pub total_bytes: u64,
+    pub total_blocks: u64,
+    pub peak_bytes: u64,
+    pub peak_blocks: u64,
+    /// Allocation sites, largest total first.
+    pub sites: Vec<Site>,
+}
+
+/// The scratch directory a profiled binary runs in, which is where dhat
+/// writes its `dhat-heap.json`.
+pub fn output_dir(year: u16, day: u8, part: u8) -> PathBuf {
+    year_dir(year)
+        .join("target/dhat-heap")
+        .join(format!("day-{day:02}-part{part}"))
+}
+
+/// Run a single part of a day under the dhat heap profiler and parse the
+/// profile it writes.
+pub fn run_part(year: u16, day: u8, part: u8) -> Result<Profile, RunnerError> {
+    let name = format!("day-{day:02}");
+    let bin = format!("part{part}");
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let manifest = year_dir(year).join("Cargo.toml");
+
+    let dir = output_dir(year, day, part);
+    fs::create_dir_all(&dir)?;
+    let path = dir.join("dhat-heap.json");
+    if path.exists() {
+        fs::remove_file(&path)?;
+    }
+
+    let output = Command::new(cargo)
+        .current_dir(&dir)
+        .arg("run")
+        .arg("-q")
+        .arg("--manifest-path")
+        .arg(&manifest)
+        .args(["--profile", "dhat", "--features", "dhat-heap"])
+        .args(["-p", &name, "--bin", &bin])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(RunnerError::CommandFailed {
+            command: format!("cargo run --profile dhat -p {name} --bin {bin}"),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        });
+    }
+
+    parse(day, part, &fs::read_to_string(path)?)
+}
+
+/// Parse a `dhat-heap.json` profile.
+///
+/// Call stacks that resolve to the same site are merged.
+/// The peak is the heap at the moment it was at its largest, which dhat
+/// records per call stack as `gb` and `gbk`.
+pub fn parse(day: u8, part: u8, json: &str) -> Result<Profile, RunnerError> {
+    let file: DhatFile = serde_json::from_str(json)?;
+
+    let mut sites: Vec<Site> = vec![];
+    for point in &file.pps {
+        let frame = site_frame(&file.ftbl, &point.fs);
+        match sites.iter_mut().find(|site| site.frame == frame) {
+            Some(site) => {
+                site.bytes += point.tb;
+                site.blocks += point.tbk;
+            }
+            None => sites.push(Site {
+                frame,
+                bytes: point.tb,
+                blocks: point.tbk,
+            }),
+        }
+    }
+    sites.sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.frame.cmp(&b.frame)));
+
+    Ok(Profile {
+        day,
+        part,
+        total_bytes: file.pps.iter().map(|point| point.tb).sum(),
+        total_blocks: file.pps.iter().map(|point| point.tbk).sum(),
+        peak_bytes: file.pps.iter().map(|point| point.gb).sum(),
+        peak_blocks: file.pps.iter().map(|point| point.gbk).sum(),
+        sites,
+    })
+}
+
+/// The innermost frame of a call stack that is not allocator or collection
+/// internals, without its address prefix.
+fn site_frame(table: &[String], frames: &[usize]) -> String {
+    let names = frames
+        .iter()
+        .filter_map(|index| table.get(*index))
+        .map(|frame| {
+            frame
+                .split_once(": ")
+                .filter(|(address, _)| address.starts_with("0x"))
+                .map_or(frame.as_str(), |(_, name)| name)
+        })
+        .collect::<Vec<_>>();
+
+    names
+        .iter()
+        .find(|name| {
+            !INTERNAL_FRAMES
+                .iter()
+                .any(|prefix| name.starts_with(prefix))
+        })
+        .or(names.first())
+        .map_or_else(|| "[unknown]".to_string(), |name| name.to_string())
+}
+
+/// Format a byte count with a binary unit.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64;
+    let mut unit = "B";
+    for next in UNITS {
+        if value < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        unit = next;
+    }
+    format!("{value:.1} {unit}")
+}
+
+/// Render the totals of a single profile followed by a markdown table of its
+/// `top` largest allocation sites.
+pub fn report(profile: &Profile, top: usize) -> String {
+    let mut report = format!(
+        "day {:02} part {}\n\
+         total: {} in {} blocks\n\
+         peak:  {} in {} blocks\n\n\
+         | bytes | blocks | site |\n\
+         |------:|-------:|:-----|\n",
+        profile.day,
+        profile.part,
+        format_bytes(profile.total_bytes),
+        profile.total_blocks,
+        format_bytes(profile.peak_bytes),
+        profile.peak_blocks,
+    );
+
+    for site in profile.sites.iter().take(top) {
+        writeln!(
+            report,
+            "| {} | {} | `{}` |",
+            format_bytes(site.bytes),
+            site.blocks,
+            site.frame
+        )
+        .expect("writing to a string cannot fail");
+    }
+
+    report
+}
+
+/// Render a markdown table summarising many profiles, heaviest total
+/// allocations first.
+pub fn summary(profiles: &[Profile]) -> String {
+    let mut profiles = profiles.iter().collect::<Vec<_>>();
+    profiles.sort_by(|a, b| {
+        b.total_bytes
+            .cmp(&a.total_bytes)
+            .then_with(|| (a.day, a.part).cmp(&(b.day, b.part)))
+    });
+
+    let mut table = String::from(
+        "| day | part | total | blocks | peak | peak blocks |\n\
+         |----:|-----:|------:|-------:|-----:|------------:|\n",
+    );
+    for profile in profiles {
+        writeln!(
+            table,
+            "| {} | {} | {} | {} | {} | {} |",
+            profile.day,
+            profile.part,
+            format_bytes(profile.total_bytes),
+            profile.total_blocks,
+            format_bytes(profile.peak_bytes),
+            profile.peak_blocks
+        )
+        .expect("writing to a string cannot fail");
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROFILE: &str = r#"{
+        "dhatFileVersion": 2,
+        "mode": "rust-heap",
+        "tg": 10,
+        "te": 20,
+        "pps": [
+            { "tb": 2048, "tbk": 4, "tl": 1, "mb": 1024, "mbk": 2, "gb": 512, "gbk": 1, "eb": 0, "ebk": 0, "fs": [1, 2, 3] },
+            { "tb": 4096, "tbk": 1, "tl": 1, "mb": 4096, "mbk": 1, "gb": 4096, "gbk": 1, "eb": 0, "ebk": 0, "fs": [1, 4] }
+        ],
+        "ftbl": [
+            "[root]",
+            "0x1: alloc::raw_vec::RawVec<T,A>::allocate_in (alloc/src/raw_vec.rs:184:45)",
+            "0x2: <alloc::vec::Vec<T> as core::clone::Clone>::clone (src/vec/mod.rs:2814:9)",
+            "0x3: day_14::part2::cycle (src/part2.rs:31:21)",
+            "0x4: day_14::parse (src/lib.rs:12:5)"
+        ]
+    }"#;
+
+    #[test]
+    fn test_parse() -> miette::Result<()> {
+        let profile = parse(14, 2, PROFILE)?;
+        assert_eq!(6144, profile.total_bytes);
+        assert_eq!(5, profile.total_blocks);
+        assert_eq!(4608, profile.peak_bytes);
+        assert_eq!(2, profile.peak_blocks);
+
+        let frames = profile
+            .sites
+            .iter()
+            .map(|site| site.frame.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                "day_14::parse (src/lib.rs:12:5)",
+                "day_14::part2::cycle (src/part2.rs:31:21)"
+            ],
+            frames
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!("512 B", format_bytes(512));
+        assert_eq!("1.5 KiB", format_bytes(1536));
+        assert_eq!("3.0 MiB", format_bytes(3 * 1024 * 1024));
+    }
+
+    #[test]
+    fn test_summary() -> miette::Result<()> {
+        let light = Profile {
+            day: 1,
+            part: 1,
+            total_bytes: 10,
+            total_blocks: 1,
+            peak_bytes: 10,
+            peak_blocks: 1,
+            sites: vec![],
+        };
+        let heavy = parse(14, 2, PROFILE)?;
+
+        let summary = summary(&[light, heavy]);
+        let rows = summary.lines().skip(2).collect::<Vec<_>>();
+        assert_eq!("| 14 | 2 | 6.0 KiB | 5 | 4.5 KiB | 2 |", rows[0]);
+        assert_eq!("| 1 | 1 | 10 B | 1 | 10 B | 1 |", rows[1]);
+        Ok(())
+    }
+}
@@ -0,0 +1,207 @@
+use std::fmt::Write;
+use std::fs;
+use std::path::PathBuf;
+
+use aoc::Answer;
+use serde::{Deserialize, Serialize};
+
+use crate::error::RunnerError;
+use crate::registry::{self, Day};
+use crate::year_dir;
+
+/// A single accepted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Recorded {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+}
+
+/// The accepted answers for every day of a year, stored in `answers.toml` at
+/// the root of the year's workspace.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default, rename = "answer")]
+    pub answers: Vec<Recorded>,
+}
+
+/// A day and part whose answer no longer matches the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub day: u8,
+    pub part: u8,
+    pub expected: String,
+    pub actual: String,
+}
+
+/// The location of the answers file for the given year.
+pub fn path(year: u16) -> PathBuf {
+    year_dir(year).join("answers.toml")
+}
+
+impl Answers {
+    /// Load the answers for the given year, or an empty set if none have
+    /// been recorded yet.
+    pub fn load(year: u16) -> Result<Self, RunnerError> {
+        let path = path(year);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn parse(input: &str) -> Result<Self, RunnerError> {
+        Ok(toml::from_str(input)?)
+    }
+
+    pub fn save(&self, year: u16) -> Result<(), RunnerError> {
+        fs::write(path(year), toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// The recorded answer for a day and part, if there is one.
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|recorded| recorded.day == day && recorded.part == part)
+            .map(|recorded| recorded.answer.as_str())
+    }
+
+    /// Record a newly accepted answer, returning `true` if it was added.
+    ///
+    /// Recording the same answer twice is a no-op, but a different answer
+    /// for an already recorded part is refused rather than overwritten.
+    pub fn record(&mut self, day: u8, part: u8, answer: &Answer) -> Result<bool, RunnerError> {
+        let answer = answer.to_string();
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Ok(false),
+            Some(expected) => Err(RunnerError::AnswerConflict {
+                day,
+                part,
+                expected: expected.to_string(),
+                actual: answer,
+            }),
+            None => {
+                self.answers.push(Recorded { day, part, answer });
+                self.answers
+                    .sort_by_key(|recorded| (recorded.day, recorded.part));
+                Ok(true)
+            }
+        }
+    }
+}
+
+/// Solve every registered day of the given year against its real input and
+/// compare the results to the recorded answers.
+///
+/// Days with no recorded answers, or no input on disk, are skipped.
+pub fn verify(year: u16) -> Result<Vec<Mismatch>, RunnerError> {
+    let answers = Answers::load(year)?;
+    let mut mismatches = vec![];
+
+    for day in registry::DAYS.iter().filter(|day| day.year == year) {
+        let parts = [1, 2]
+            .into_iter()
+            .filter(|part| answers.get(day.day, *part).is_some())
+            .collect::<Vec<u8>>();
+
+        if parts.is_empty() || !day.input_path().exists() {
+            continue;
+        }
+
+        mismatches.extend(check(day, &answers, &parts)?);
+    }
+
+    Ok(mismatches)
+}
+
+fn check(day: &Day, answers: &Answers, parts: &[u8]) -> Result<Vec<Mismatch>, RunnerError> {
+    let input = fs::read_to_string(day.input_path())?;
+
+    let solved = match (day.solve)(&input, parts) {
+        Ok(solved) => solved
+            .parts
+            .into_iter()
+            .map(|(part, answer, _)| (part, answer.to_string()))
+            .collect(),
+        Err(error) => parts
+            .iter()
+            .map(|part| (*part, format!("error: {error}")))
+            .collect::<Vec<_>>(),
+    };
+
+    Ok(solved
+        .into_iter()
+        .filter_map(|(part, actual)| {
+            let expected = answers.get(day.day, part)?;
+            (expected != actual).then(|| Mismatch {
+                day: day.day,
+                part,
+                expected: expected.to_string(),
+                actual,
+            })
+        })
+        .collect())
+}
+
+/// Render mismatched answers as a table.
+pub fn diff_table(mismatches: &[Mismatch]) -> String {
+    let expected_width = mismatches
+        .iter()
+        .map(|mismatch| mismatch.expected.len())
+        .chain(["expected".len()])
+        .max()
+        .unwrap_or_default();
+
+    let mut table = format!("| day | part | {:expected_width$} | actual\n", "expected");
+    for mismatch in mismatches {
+        writeln!(
+            table,
+            "| {:>3} | {:>4} | {:expected_width$} | {}",
+            mismatch.day, mismatch.part, mismatch.expected, mismatch.actual
+        )
+        .expect("writing to a string cannot fail");
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record() -> miette::Result<()> {
+        let mut answers = Answers::parse(
+            r#"
+            [[answer]]
+            day = 2
+            part = 1
+            answer = "321"
+            "#,
+        )?;
+
+        assert!(answers.record(1, 2, &Answer::Integer(42))?);
+        assert!(!answers.record(1, 2, &Answer::Integer(42))?);
+        assert!(answers.record(2, 1, &Answer::Integer(7)).is_err());
+
+        assert_eq!(Some("42"), answers.get(1, 2));
+        assert_eq!(Some("321"), answers.get(2, 1));
+        assert_eq!(None, answers.get(3, 1));
+        assert_eq!(1, answers.answers[0].day);
+        Ok(())
+    }
+
+    #[test]
+    fn test_diff_table() {
+        let mismatches = vec![Mismatch {
+            day: 4,
+            part: 2,
+            expected: "2005".to_string(),
+            actual: "2004".to_string(),
+        }];
+        assert_eq!(
+            "| day | part | expected | actual\n|   4 |    2 | 2005     | 2004\n",
+            diff_table(&mismatches)
+        );
+    }
+}
@@ -0,0 +1,329 @@
+use std::fmt::Write as _;
+use std::fs::{self, OpenOptions};
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::error::RunnerError;
+use crate::year_dir;
+
+const HEADER: &str = "timestamp,day,bench,fastest_ns,median_ns,slowest_ns";
+
+/// The timings of a single divan benchmark.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sample {
+    pub day: u8,
+    pub bench: String,
+    pub fastest: Duration,
+    pub median: Duration,
+    pub slowest: Duration,
+}
+
+/// A recorded benchmark run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub timestamp: u64,
+    pub samples: Vec<Sample>,
+}
+
+/// The location of the benchmark history for the given year.
+pub fn history_path(year: u16) -> PathBuf {
+    year_dir(year).join("bench-history.csv")
+}
+
+/// Every day crate in the year's workspace, excluding the `day-00` template.
+pub fn days(year: u16) -> Result<Vec<u8>, RunnerError> {
+    let mut days = fs::read_dir(year_dir(year))?
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name();
+            name.to_str()?.strip_prefix("day-")?.parse::<u8>().ok()
+        })
+        .filter(|day| *day != 0)
+        .collect::<Vec<_>>();
+    days.sort();
+    Ok(days)
+}
+
+/// Run the divan benchmarks of a single day crate and collect the results.
+pub fn run_day(year: u16, day: u8) -> Result<Vec<Sample>, RunnerError> {
+    let name = format!("day-{day:02}");
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+
+    let output = Command::new(cargo)
+        .current_dir(year_dir(year))
+        .args(["bench", "-q", "-p", &name, "--bench", &name])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(RunnerError::CommandFailed {
+            command: format!("cargo bench -p {name}"),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        });
+    }
+
+    Ok(parse_divan(day, &String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Parse the table divan prints into samples.
+///
+/// Each benchmark is a row of the form
+/// `├─ part1  1.2 ms │ 3.4 ms │ 2.1 ms │ 2.2 ms │ 100 │ 100`, holding the
+/// fastest, slowest, median and mean times.  Module groups and benchmarks
+/// with arguments are rows without times, with their benchmarks nested
+/// below them, and those benchmarks are named by their full path, such as
+/// `parse/10`.
+pub fn parse_divan(day: u8, output: &str) -> Vec<Sample> {
+    let mut groups: Vec<String> = vec![];
+
+    output
+        .lines()
+        .filter_map(|line| {
+            let (indent, row) = line.split_once("├─").or_else(|| line.split_once("╰─"))?;
+            if !indent.chars().all(|c| c == '│' || c.is_whitespace()) {
+                return None;
+            }
+            // Every level of nesting is indented by three columns.
+            groups.truncate(indent.chars().count() / 3);
+
+            let columns = row.split('│').map(str::trim).collect::<Vec<_>>();
+            let first = columns.first()?;
+            let Some((name, fastest)) = first.split_once(char::is_whitespace) else {
+                groups.push(first.to_string());
+                return None;
+            };
+
+            let path = groups.iter().map(String::as_str).chain([name]);
+            Some(Sample {
+                day,
+                bench: path.collect::<Vec<_>>().join("/"),
+                fastest: parse_duration(fastest.trim())?,
+                slowest: parse_duration(columns.get(1)?)?,
+                median: parse_duration(columns.get(2)?)?,
+            })
+        })
+        .collect()
+}
+
+/// Parse a divan duration such as `152.9 µs` or `1.852 ms`.
+pub fn parse_duration(input: &str) -> Option<Duration> {
+    let (value, unit) = input.split_once(' ')?;
+    let value = value.parse::<f64>().ok()?;
+    let nanos = match unit {
+        "ps" => value / 1000.0,
+        "ns" => value,
+        "µs" | "us" => value * 1e3,
+        "ms" => value * 1e6,
+        "s" => value * 1e9,
+        _ => return None,
+    };
+    Some(Duration::from_nanos(nanos.round() as u64))
+}
+
+/// Load every previous run from the history file.
+pub fn load_history(path: &Path) -> Result<Vec<Run>, RunnerError> {
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    Ok(parse_history(&fs::read_to_string(path)?))
+}
+
+/// Parse the CSV history, grouping rows into runs by timestamp.
+pub fn parse_history(input: &str) -> Vec<Run> {
+    let mut runs: Vec<Run> = vec![];
+
+    for line in input.lines().filter(|line| *line != HEADER) {
+        let fields = line.split(',').collect::<Vec<_>>();
+        let [timestamp, day, bench, fastest, median, slowest] = fields[..] else {
+            continue;
+        };
+        let (Ok(timestamp), Ok(day), Ok(fastest), Ok(median), Ok(slowest)) = (
+            timestamp.parse(),
+            day.parse(),
+            fastest.parse(),
+            median.parse(),
+            slowest.parse(),
+        ) else {
+            continue;
+        };
+
+        let sample = Sample {
+            day,
+            bench: bench.to_string(),
+            fastest: Duration::from_nanos(fastest),
+            median: Duration::from_nanos(median),
+            slowest: Duration::from_nanos(slowest),
+        };
+
+        match runs.iter_mut().find(|run| run.timestamp == timestamp) {
+            Some(run) => run.samples.push(sample),
+            None => runs.push(Run {
+                timestamp,
+                samples: vec![sample],
+            }),
+        }
+    }
+
+    runs.sort_by_key(|run| run.timestamp);
+    runs
+}
+
+/// Append a run to the history file, creating it if needed.
+pub fn append_history(path: &Path, samples: &[Sample]) -> Result<Run, RunnerError> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+
+    let new = !path.exists();
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    if new {
+        writeln!(file, "{HEADER}")?;
+    }
+    for sample in samples {
+        writeln!(
+            file,
+            "{timestamp},{},{},{},{},{}",
+            sample.day,
+            sample.bench,
+            sample.fastest.as_nanos(),
+            sample.median.as_nanos(),
+            sample.slowest.as_nanos()
+        )?;
+    }
+
+    Ok(Run {
+        timestamp,
+        samples: samples.to_vec(),
+    })
+}
+
+/// Render a markdown table of the current run, comparing each median with
+/// the most recent previous run that includes the same benchmark.
+///
+/// Slowdowns greater than `threshold` percent are flagged.
+pub fn report(current: &[Sample], history: &[Run], threshold: f64) -> String {
+    let mut table = String::from(
+        "| day | bench | median | fastest | slowest | Δ median |\n\
+         |----:|:------|-------:|--------:|--------:|---------:|\n",
+    );
+
+    for sample in current {
+        let previous = history.iter().rev().find_map(|run| {
+            run.samples
+                .iter()
+                .find(|previous| previous.day == sample.day && previous.bench == sample.bench)
+        });
+
+        let delta = match previous {
+            Some(previous) if !previous.median.is_zero() => {
+                let change =
+                    (sample.median.as_secs_f64() / previous.median.as_secs_f64() - 1.0) * 100.0;
+                let flag = if change > threshold { " ⚠️" } else { "" };
+                format!("{change:+.1}%{flag}")
+            }
+            _ => "new".to_string(),
+        };
+
+        writeln!(
+            table,
+            "| {} | {} | {:.1?} | {:.1?} | {:.1?} | {} |",
+            sample.day, sample.bench, sample.median, sample.fastest, sample.slowest, delta
+        )
+        .expect("writing to a string cannot fail");
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OUTPUT: &str = "\
+day_02    fastest       │ slowest       │ median        │ mean          │ samples │ iters
+├─ parse  146.2 µs      │ 208.4 µs      │ 152.9 µs      │ 155.6 µs      │ 100     │ 100
+╰─ part2  619.7 µs      │ 1.852 ms      │ 723.6 µs      │ 833.9 µs      │ 100     │ 100
+";
+
+    fn sample(bench: &str, median: u64) -> Sample {
+        Sample {
+            day: 2,
+            bench: bench.to_string(),
+            fastest: Duration::from_micros(median / 2),
+            median: Duration::from_micros(median),
+            slowest: Duration::from_micros(median * 2),
+        }
+    }
+
+    #[test]
+    fn test_parse_divan() {
+        let samples = parse_divan(2, OUTPUT);
+        assert_eq!(2, samples.len());
+        assert_eq!("parse", samples[0].bench);
+        assert_eq!(Duration::from_nanos(146_200), samples[0].fastest);
+        assert_eq!(Duration::from_nanos(152_900), samples[0].median);
+        assert_eq!(Duration::from_nanos(1_852_000), samples[1].slowest);
+    }
+
+    #[test]
+    fn test_parse_divan_nested() {
+        let output = "\
+day_11         fastest       │ slowest       │ median        │ mean          │ samples │ iters
+├─ blink                     │               │               │               │         │
+│  ├─ 25       1 ms          │ 3 ms          │ 2 ms          │ 2 ms          │ 100     │ 100
+│  ╰─ 75       10 ms         │ 30 ms         │ 20 ms         │ 20 ms         │ 100     │ 100
+├─ parts                     │               │               │               │         │
+│  ╰─ nested                 │               │               │               │         │
+│     ╰─ part1 4 µs          │ 6 µs          │ 5 µs          │ 5 µs          │ 100     │ 100
+╰─ parse       7 µs          │ 9 µs          │ 8 µs          │ 8 µs          │ 100     │ 100
+";
+        let samples = parse_divan(11, output);
+        let names = samples
+            .iter()
+            .map(|sample| sample.bench.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec!["blink/25", "blink/75", "parts/nested/part1", "parse"],
+            names
+        );
+        assert_eq!(Duration::from_millis(20), samples[1].median);
+        assert_eq!(Duration::from_micros(6), samples[2].slowest);
+    }
+
+    #[test]
+    fn test_history_round_trip() {
+        let history = format!("{HEADER}\n10,2,parse,1,2,3\n10,2,part1,4,5,6\n20,2,parse,7,8,9\n");
+        let runs = parse_history(&history);
+        assert_eq!(2, runs.len());
+        assert_eq!(2, runs[0].samples.len());
+        assert_eq!(Duration::from_nanos(8), runs[1].samples[0].median);
+    }
+
+    #[test]
+    fn test_report() {
+        let history = vec![
+            Run {
+                timestamp: 1,
+                samples: vec![sample("part1", 100), sample("part2", 100)],
+            },
+            Run {
+                timestamp: 2,
+                samples: vec![sample("part1", 200)],
+            },
+        ];
+        let current = vec![
+            sample("part1", 210),
+            sample("part2", 90),
+            sample("parse", 5),
+        ];
+
+        let report = report(&current, &history, 10.0);
+        let rows = report.lines().skip(2).collect::<Vec<_>>();
+        assert!(rows[0].ends_with("| +5.0% |"), "{}", rows[0]);
+        assert!(rows[1].ends_with("| -10.0% |"), "{}", rows[1]);
+        assert!(rows[2].ends_with("| new |"), "{}", rows[2]);
+
+        let report = super::report(&current, &history[..1], 10.0);
+        assert!(report.contains("+110.0% ⚠️"));
+    }
+}
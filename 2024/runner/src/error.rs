@@ -0,0 +1,62 @@
+use std::path::PathBuf;
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+#[derive(Error, Diagnostic, Debug)]
+pub enum RunnerError {
+    #[error(transparent)]
+    #[diagnostic(code(runner::io_error))]
+    IoError(#[from] std::io::Error),
+
+    #[error("no solution registered for {year} day {day}")]
+    #[diagnostic(
+        code(runner::unknown_day),
+        help("add the day to `DAYS` in runner/src/registry.rs")
+    )]
+    UnknownDay { year: u16, day: u8 },
+
+    #[error("invalid part {0}, expected 1 or 2")]
+    #[diagnostic(code(runner::invalid_part))]
+    InvalidPart(u8),
+
+    #[error("{} already exists", .0.display())]
+    #[diagnostic(code(runner::day_exists))]
+    DayExists(PathBuf),
+
+    #[error("no template found at {}", .0.display())]
+    #[diagnostic(code(runner::missing_template))]
+    MissingTemplate(PathBuf),
+
+    #[error("could not find the `DAYS` table in {}", .0.display())]
+    #[diagnostic(code(runner::missing_registry))]
+    MissingRegistry(PathBuf),
+
+    #[error("day {day} part {part} was recorded as {expected}, not {actual}")]
+    #[diagnostic(
+        code(runner::answer_conflict),
+        help("edit answers.toml by hand if the recorded answer is wrong")
+    )]
+    AnswerConflict {
+        day: u8,
+        part: u8,
+        expected: String,
+        actual: String,
+    },
+
+    #[error(transparent)]
+    #[diagnostic(code(runner::toml_error))]
+    TomlError(#[from] toml::de::Error),
+
+    #[error(transparent)]
+    #[diagnostic(code(runner::toml_error))]
+    TomlSerializeError(#[from] toml::ser::Error),
+
+    #[error(transparent)]
+    #[diagnostic(code(runner::json_error))]
+    JsonError(#[from] serde_json::Error),
+
+    #[error("`{command}` failed")]
+    #[diagnostic(code(runner::command_failed), help("{stderr}"))]
+    CommandFailed { command: String, stderr: String },
+}
@@ -0,0 +1,22 @@
+use std::path::PathBuf;
+
+pub mod alloc;
+pub mod answers;
+pub mod bench;
+pub mod error;
+pub mod registry;
+pub mod scaffold;
+
+/// The directory containing the runner crate.
+pub fn runner_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// The workspace directory holding every day of the given year.
+pub fn year_dir(year: u16) -> PathBuf {
+    runner_dir()
+        .ancestors()
+        .nth(2)
+        .expect("the runner lives inside a year workspace")
+        .join(year.to_string())
+}
@@ -0,0 +1,264 @@
+use std::io::Read;
+use std::path::PathBuf;
+
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
+use miette::{Context, IntoDiagnostic};
+use runner::answers::Answers;
+use runner::{alloc, bench, registry, scaffold};
+
+fn cli() -> Command {
+    Command::new("aoc")
+        .about("Run Advent of Code solutions")
+        .subcommand_required(true)
+        .subcommand(
+            Command::new("run")
+                .about("Solve a single day")
+                .arg(
+                    Arg::new("year")
+                        .long("year")
+                        .value_parser(value_parser!(u16))
+                        .default_value("2024"),
+                )
+                .arg(
+                    Arg::new("day")
+                        .long("day")
+                        .value_parser(value_parser!(u8).range(1..=25))
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("part")
+                        .long("part")
+                        .help("Only run this part, both parts are run by default")
+                        .value_parser(value_parser!(u8).range(1..=2)),
+                )
+                .arg(
+                    Arg::new("input")
+                        .long("input")
+                        .help("Read the puzzle input from this file, or `-` for stdin")
+                        .value_parser(value_parser!(PathBuf)),
+                )
+                .arg(
+                    Arg::new("record")
+                        .long("record")
+                        .help("Record the answers as accepted in the year's answers.toml")
+                        .action(ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("bench")
+                .about("Run the benchmarks of every day and compare with the last run")
+                .arg(
+                    Arg::new("year")
+                        .long("year")
+                        .value_parser(value_parser!(u16))
+                        .default_value("2024"),
+                )
+                .arg(
+                    Arg::new("day")
+                        .long("day")
+                        .help("Only benchmark this day")
+                        .value_parser(value_parser!(u8).range(1..=25)),
+                )
+                .arg(
+                    Arg::new("threshold")
+                        .long("threshold")
+                        .help("Flag medians that slowed down by more than this percentage")
+                        .value_parser(value_parser!(f64))
+                        .default_value("10"),
+                ),
+        )
+        .subcommand(
+            Command::new("alloc")
+                .about("Profile the heap allocations of a day with dhat")
+                .arg(
+                    Arg::new("year")
+                        .long("year")
+                        .value_parser(value_parser!(u16))
+                        .default_value("2024"),
+                )
+                .arg(
+                    Arg::new("day")
+                        .long("day")
+                        .value_parser(value_parser!(u8).range(1..=25))
+                        .required_unless_present("all"),
+                )
+                .arg(
+                    Arg::new("part")
+                        .long("part")
+                        .help("Only profile this part, both parts are profiled by default")
+                        .value_parser(value_parser!(u8).range(1..=2)),
+                )
+                .arg(
+                    Arg::new("all")
+                        .long("all")
+                        .help("Profile every day and print a summary table")
+                        .conflicts_with("day")
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("top")
+                        .long("top")
+                        .help("The number of allocation sites to show")
+                        .value_parser(value_parser!(usize))
+                        .default_value("10"),
+                ),
+        )
+        .subcommand(
+            Command::new("new")
+                .about("Create a new day from the day-00 template")
+                .arg(
+                    Arg::new("year")
+                        .long("year")
+                        .value_parser(value_parser!(u16))
+                        .default_value("2024"),
+                )
+                .arg(
+                    Arg::new("day")
+                        .long("day")
+                        .value_parser(value_parser!(u8).range(1..=25))
+                        .required(true),
+                ),
+        )
+}
+
+/// Read the puzzle input from the given path, stdin, or the day's default
+/// `input.txt`.
+fn read_input(path: Option<&PathBuf>, day: &registry::Day) -> miette::Result<String> {
+    match path {
+        Some(path) if path.as_os_str() == "-" => {
+            let mut input = String::new();
+            std::io::stdin()
+                .read_to_string(&mut input)
+                .into_diagnostic()
+                .context("read input from stdin")?;
+            Ok(input)
+        }
+        Some(path) => std::fs::read_to_string(path)
+            .into_diagnostic()
+            .with_context(|| format!("read input from {}", path.display())),
+        None => {
+            let path = day.input_path();
+            std::fs::read_to_string(&path)
+                .into_diagnostic()
+                .with_context(|| format!("read input from {}", path.display()))
+        }
+    }
+}
+
+fn run(args: &ArgMatches) -> miette::Result<()> {
+    let year = *args.get_one::<u16>("year").expect("year has a default");
+    let day = *args.get_one::<u8>("day").expect("day is required");
+
+    let entry = registry::find(year, day)?;
+    let input = read_input(args.get_one::<PathBuf>("input"), entry)?;
+
+    let parts = match args.get_one::<u8>("part") {
+        Some(part) => vec![*part],
+        None => vec![1, 2],
+    };
+
+    let solved =
+        (entry.solve)(&input, &parts).with_context(|| format!("solve {year} day {day}"))?;
+
+    println!("{year} day {day:02} parsed ({:?})", solved.parse);
+    for (part, answer, elapsed) in &solved.parts {
+        println!("{year} day {day:02} part {part}: {answer} ({elapsed:?})");
+    }
+
+    if args.get_flag("record") {
+        let mut answers = Answers::load(year)?;
+        for (part, answer, _) in &solved.parts {
+            if answers.record(day, *part, answer)? {
+                println!("recorded {year} day {day:02} part {part}");
+            }
+        }
+        answers.save(year)?;
+    }
+
+    Ok(())
+}
+
+fn bench(args: &ArgMatches) -> miette::Result<()> {
+    let year = *args.get_one::<u16>("year").expect("year has a default");
+    let threshold = *args
+        .get_one::<f64>("threshold")
+        .expect("threshold has a default");
+
+    let days = match args.get_one::<u8>("day") {
+        Some(day) => vec![*day],
+        None => bench::days(year)?,
+    };
+
+    let mut samples = vec![];
+    for day in days {
+        eprintln!("benchmarking {year} day {day:02}");
+        samples.extend(bench::run_day(year, day)?);
+    }
+
+    let path = bench::history_path(year);
+    let history = bench::load_history(&path)?;
+    bench::append_history(&path, &samples)?;
+
+    print!("{}", bench::report(&samples, &history, threshold));
+    Ok(())
+}
+
+fn alloc(args: &ArgMatches) -> miette::Result<()> {
+    let year = *args.get_one::<u16>("year").expect("year has a default");
+    let top = *args.get_one::<usize>("top").expect("top has a default");
+
+    let parts = match args.get_one::<u8>("part") {
+        Some(part) => vec![*part],
+        None => vec![1, 2],
+    };
+
+    if args.get_flag("all") {
+        let mut profiles = vec![];
+        for day in bench::days(year)? {
+            for part in &parts {
+                eprintln!("profiling {year} day {day:02} part {part}");
+                profiles.push(alloc::run_part(year, day, *part)?);
+            }
+        }
+        print!("{}", alloc::summary(&profiles));
+        return Ok(());
+    }
+
+    let day = *args
+        .get_one::<u8>("day")
+        .expect("day is required without --all");
+    for (index, part) in parts.iter().enumerate() {
+        if index > 0 {
+            println!();
+        }
+        let profile = alloc::run_part(year, day, *part)?;
+        print!("{}", alloc::report(&profile, top));
+    }
+    Ok(())
+}
+
+fn new(args: &ArgMatches) -> miette::Result<()> {
+    let year = *args.get_one::<u16>("year").expect("year has a default");
+    let day = *args.get_one::<u8>("day").expect("day is required");
+
+    let path = scaffold::new_day(year, day)?;
+    println!("created {}", path.display());
+
+    if scaffold::register(year, day)? {
+        println!("registered {year} day {day:02} with the runner");
+    } else {
+        println!("{year} is not in the runner's workspace, not registering");
+    }
+
+    Ok(())
+}
+
+fn main() -> miette::Result<()> {
+    match cli().get_matches().subcommand() {
+        Some(("run", args)) => run(args),
+        Some(("bench", args)) => bench(args),
+        Some(("alloc", args)) => alloc(args),
+        Some(("new", args)) => new(args),
+        _ => unreachable!("a subcommand is required"),
+    }
+}
@@ -0,0 +1,123 @@
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use aoc::{Answer, Solution};
+use miette::Diagnostic;
+
+use crate::error::RunnerError;
+use crate::year_dir;
+
+/// Parse the input and solve the requested parts, timing each step.
+pub type Solve = fn(&str, &[u8]) -> miette::Result<Solved>;
+
+/// The outcome of solving one or more parts of a day.
+#[derive(Debug)]
+pub struct Solved {
+    pub parse: Duration,
+    pub parts: Vec<(u8, Answer, Duration)>,
+}
+
+/// The solution registered for a single day of a single year.
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub solve: Solve,
+}
+
+impl Day {
+    /// The location of the day's puzzle input in the repository.
+    pub fn input_path(&self) -> PathBuf {
+        year_dir(self.year)
+            .join(format!("day-{:02}", self.day))
+            .join("input.txt")
+    }
+}
+
+/// Parse the input once and run each of the requested parts against it.
+fn solve<S>(input: &str, parts: &[u8]) -> miette::Result<Solved>
+where
+    S: Solution,
+    S::Error: Diagnostic + Send + Sync + 'static,
+{
+    if let Some(part) = parts.iter().find(|part| !(1..=2).contains(*part)) {
+        return Err(RunnerError::InvalidPart(*part).into());
+    }
+
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => S::part1(&parsed)?,
+                _ => S::part2(&parsed)?,
+            };
+            Ok((part, answer, start.elapsed()))
+        })
+        .collect::<miette::Result<_>>()?;
+
+    Ok(Solved { parse, parts })
+}
+
+/// Register a day crate by its `Puzzle` solution.
+macro_rules! day {
+    ($year:literal, $day:literal, $krate:ident) => {
+        Day {
+            year: $year,
+            day: $day,
+            solve: solve::<$krate::Puzzle>,
+        }
+    };
+}
+
+/// Every day the runner knows how to solve.
+///
+/// Only days in this workspace can be linked; the 2022 and 2023 solutions
+/// live in their own workspaces with incompatible dependency versions.
+pub const DAYS: &[Day] = &[
+    day!(2024, 1, day_01),
+    day!(2024, 2, day_02),
+    day!(2024, 3, day_03),
+    day!(2024, 4, day_04),
+];
+
+/// Look up the solutions for the given year and day.
+pub fn find(year: u16, day: u8) -> Result<&'static Day, RunnerError> {
+    DAYS.iter()
+        .find(|entry| entry.year == year && entry.day == day)
+        .ok_or(RunnerError::UnknownDay { year, day })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find() -> miette::Result<()> {
+        let day = find(2024, 4)?;
+        let solved = (day.solve)(include_str!("../../day-04/test-input.txt"), &[1, 2])?;
+        let answers = solved
+            .parts
+            .into_iter()
+            .map(|(part, answer, _)| (part, answer))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![(1, Answer::Integer(18)), (2, Answer::Integer(9))],
+            answers
+        );
+        assert!(day.input_path().ends_with("2024/day-04/input.txt"));
+
+        assert!(matches!(
+            find(2024, 26),
+            Err(RunnerError::UnknownDay {
+                year: 2024,
+                day: 26
+            })
+        ));
+        assert!((day.solve)("", &[3]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,202 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::RunnerError;
+use crate::{runner_dir, year_dir};
+
+/// The day crate every new day is copied from.
+const TEMPLATE: &str = "day-00";
+
+/// Create a new day crate for the given year by copying the `day-00`
+/// template, returning the path of the new crate.
+///
+/// The crate name, bench name and `day_00` imports are rewritten to match
+/// the new day.  An existing directory is never overwritten, and a partly
+/// copied directory is removed again if copying fails.
+pub fn new_day(year: u16, day: u8) -> Result<PathBuf, RunnerError> {
+    let template = year_dir(year).join(TEMPLATE);
+    if !template.is_dir() {
+        return Err(RunnerError::MissingTemplate(template));
+    }
+
+    let target = year_dir(year).join(format!("day-{day:02}"));
+    if target.exists() {
+        return Err(RunnerError::DayExists(target));
+    }
+
+    if let Err(error) = copy_template(&template, &target, day) {
+        // Leave nothing behind that would block a retry.
+        let _ = fs::remove_dir_all(&target);
+        return Err(error);
+    }
+    Ok(target)
+}
+
+/// Register a new day with the runner by adding it to the `DAYS` table and
+/// the runner's dependencies.
+///
+/// Only days in the runner's own workspace can be linked, so this returns
+/// `false` without changing anything for any other year.
+pub fn register(year: u16, day: u8) -> Result<bool, RunnerError> {
+    if runner_dir().parent() != Some(year_dir(year).as_path()) {
+        return Ok(false);
+    }
+
+    let registry_path = runner_dir().join("src/registry.rs");
+    let registry = fs::read_to_string(&registry_path)?;
+    let registry = register_day(&registry, year, day)
+        .ok_or_else(|| RunnerError::MissingRegistry(registry_path.clone()))?;
+
+    let manifest_path = runner_dir().join("Cargo.toml");
+    let manifest = add_dependency(&fs::read_to_string(&manifest_path)?, day);
+
+    fs::write(registry_path, registry)?;
+    fs::write(manifest_path, manifest)?;
+    Ok(true)
+}
+
+/// Recursively copy the template, rendering every text file that mentions
+/// the template day and copying everything else as is.
+fn copy_template(from: &Path, to: &Path, day: u8) -> Result<(), RunnerError> {
+    fs::create_dir_all(to)?;
+
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let path = entry.path();
+        let target = to.join(entry.file_name());
+
+        if entry.file_type()?.is_dir() {
+            if entry.file_name() != "target" {
+                copy_template(&path, &target, day)?;
+            }
+        } else {
+            match String::from_utf8(fs::read(&path)?) {
+                Ok(text) if text.contains("day-00") || text.contains("day_00") => {
+                    fs::write(target, render(&text, day))?
+                }
+                _ => {
+                    fs::copy(&path, &target)?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Replace the template's crate and module names with the new day's.
+pub fn render(template: &str, day: u8) -> String {
+    template
+        .replace("day-00", &format!("day-{day:02}"))
+        .replace("day_00", &format!("day_{day:02}"))
+}
+
+/// Add a day to the `DAYS` table in the registry source, keeping the entries
+/// sorted.  Returns `None` if the table could not be found.
+pub fn register_day(registry: &str, year: u16, day: u8) -> Option<String> {
+    let start = registry.find("pub const DAYS: &[Day] = &[\n")?;
+    let body_start = start + registry[start..].find('\n')? + 1;
+    let body_end = body_start + registry[body_start..].find("];")?;
+
+    let mut entries = registry[body_start..body_end]
+        .lines()
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+
+    let entry = format!("    day!({year}, {day}, day_{day:02}),");
+    if !entries.contains(&entry) {
+        entries.push(entry);
+    }
+    entries.sort_by_key(|entry| {
+        entry
+            .split(|c: char| !c.is_ascii_digit())
+            .filter(|number| !number.is_empty())
+            .map(|number| number.parse::<u32>().unwrap_or_default())
+            .take(2)
+            .collect::<Vec<_>>()
+    });
+
+    Some(format!(
+        "{}{}\n{}",
+        &registry[..body_start],
+        entries.join("\n"),
+        &registry[body_end..]
+    ))
+}
+
+/// Add a path dependency on the day crate to the runner's manifest, keeping
+/// the day dependencies sorted.
+pub fn add_dependency(manifest: &str, day: u8) -> String {
+    let dependency = format!("day-{day:02} = {{ path = \"../day-{day:02}\" }}");
+    if manifest.lines().any(|line| line == dependency) {
+        return manifest.to_string();
+    }
+
+    let mut lines = manifest.lines().map(str::to_string).collect::<Vec<_>>();
+    let position = lines
+        .iter()
+        .position(|line| line.starts_with("day-") && *line > dependency)
+        .or_else(|| {
+            lines
+                .iter()
+                .rposition(|line| line.starts_with("day-"))
+                .map(|last| last + 1)
+        })
+        .unwrap_or(lines.len());
+    lines.insert(position, dependency);
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let template = "name = \"day-00\"\nuse day_00::part1::process;\n";
+        assert_eq!(
+            "name = \"day-05\"\nuse day_05::part1::process;\n",
+            render(template, 5)
+        );
+    }
+
+    #[test]
+    fn test_copy_template() -> Result<(), RunnerError> {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let (template, target) = (root.join("day-00"), root.join("day-05"));
+        fs::create_dir_all(template.join("src"))?;
+        fs::write(template.join("src/lib.rs"), "use day_00::part1;\n")?;
+        fs::write(template.join("input.bin"), [0xff, 0xfe, 0x00])?;
+
+        let copied = copy_template(&template, &target, 5).and_then(|()| {
+            Ok((
+                fs::read_to_string(target.join("src/lib.rs"))?,
+                fs::read(target.join("input.bin"))?,
+            ))
+        });
+        fs::remove_dir_all(&root)?;
+
+        let (lib, input) = copied?;
+        assert_eq!("use day_05::part1;\n", lib);
+        assert_eq!(vec![0xff, 0xfe, 0x00], input);
+        Ok(())
+    }
+
+    #[test]
+    fn test_register_day() {
+        let registry = "pub const DAYS: &[Day] = &[\n    day!(2024, 1, day_01),\n    day!(2024, 12, day_12),\n];\n";
+        let expected = "pub const DAYS: &[Day] = &[\n    day!(2024, 1, day_01),\n    day!(2024, 5, day_05),\n    day!(2024, 12, day_12),\n];\n";
+        assert_eq!(Some(expected.to_string()), register_day(registry, 2024, 5));
+        assert_eq!(Some(expected.to_string()), register_day(expected, 2024, 5));
+        assert_eq!(None, register_day("", 2024, 5));
+    }
+
+    #[test]
+    fn test_add_dependency() {
+        let manifest = "[dependencies]\naoc = { path = \"../aoc\" }\nday-01 = { path = \"../day-01\" }\nday-12 = { path = \"../day-12\" }\n";
+        let expected = "[dependencies]\naoc = { path = \"../aoc\" }\nday-01 = { path = \"../day-01\" }\nday-05 = { path = \"../day-05\" }\nday-12 = { path = \"../day-12\" }\n";
+        assert_eq!(expected, add_dependency(manifest, 5));
+        assert_eq!(expected, add_dependency(expected, 5));
+    }
+}
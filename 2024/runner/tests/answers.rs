@@ -0,0 +1,20 @@
+use runner::answers;
+use runner::registry::DAYS;
+
+/// Every registered day must still produce its recorded answer on the real
+/// input.
+#[test]
+fn test_recorded_answers() -> miette::Result<()> {
+    let mut years = DAYS.iter().map(|day| day.year).collect::<Vec<_>>();
+    years.dedup();
+
+    for year in years {
+        let mismatches = answers::verify(year)?;
+        assert!(
+            mismatches.is_empty(),
+            "{year} answers have changed:\n{}",
+            answers::diff_table(&mismatches)
+        );
+    }
+    Ok(())
+}